@@ -50,6 +50,11 @@ export interface JsDuplicateDependencyPluginResp {
   duration: number
 }
 
+export interface JsFileDuration {
+  filePath: string
+  durationMs: number
+}
+
 export interface JsHeatmapNode {
   name: string
   value: number
@@ -68,6 +73,44 @@ export interface JsLibraryGroup {
   libs: Array<JsLibrary>
 }
 
+export interface JsLintedFile {
+  filePath: string
+  messages: Array<JsLintMessage>
+}
+
+export interface JsLintMessage {
+  /** 规则名，如 eslint/no-debugger */
+  ruleId?: string
+  severity: 'error' | 'warning'
+  message: string
+  help?: string
+  /** 起始偏移量（字节） */
+  start: number
+  /** 结束偏移量（字节） */
+  end: number
+  /** 起始行号（从 1 开始） */
+  line: number
+  /** 起始列号（从 1 开始） */
+  column: number
+  endLine: number
+  endColumn: number
+  /** 是否有可用的自动修复 */
+  fixable: boolean
+}
+
+export interface JsLintStatsSummary {
+  /** 所有 lint（首次 + 增量）的累计耗时 */
+  totalDurationMs: number
+  /** 累计 lint 次数（同一文件多次 lint 计多次） */
+  lintCount: number
+  /** 命中持久化缓存的次数 */
+  cacheHits: number
+  /** 最近一次 lint 耗时最长的文件（降序） */
+  slowestFiles: Array<JsFileDuration>
+  /** 当前结果中每条规则的命中次数（降序），不包括规则的耗时 */
+  rules: Array<JsRuleHits>
+}
+
 export interface JsModuleInfo {
   id: string
   name: string
@@ -79,6 +122,19 @@ export interface JsModuleInfo {
   dependencies: Array<string>
 }
 
+export interface JsOxlintPluginResp {
+  files: Array<JsLintedFile>
+  errorCount: number
+  warningCount: number
+  /** lint 统计汇总（开启 stats 时） */
+  stats?: JsLintStatsSummary
+}
+
+export interface JsRuleHits {
+  ruleId: string
+  count: number
+}
+
 export interface JsSizeInfo {
   original: number
   minified: number
@@ -128,6 +184,13 @@ export interface RawCaseSensitivePathsPluginOpts {
 
 }
 
+export interface RawChangedFilesOpts {
+  /** git 对比基准（分支、tag 或 commit），与 HEAD 求 merge-base 后取差异文件，默认为 main */
+  base?: string
+  /** 直接指定需要 lint 的文件列表（相对路径基于 rspack 的 context），优先级高于 base */
+  files?: Array<string>
+}
+
 export interface RawDemoPluginOpts {
   onDetected?: ((err: Error | null, arg: RawDemoResponse) => Promise<undefined>)
 }
@@ -150,6 +213,12 @@ export interface RawEnvironment {
   sharedNodeBrowser?: boolean
 }
 
+export interface RawLintReport {
+  format: 'json' | 'sarif' | 'junit' | 'checkstyle'
+  /** 报告文件路径，相对路径基于 rspack 的 context */
+  outputPath: string
+}
+
 export interface RawOxlintPluginOpts {
   /** runtime 文件的生成目录 , 请保证存在 @@ 的 alias 配置 */
   outputDir: string
@@ -159,11 +228,104 @@ export interface RawOxlintPluginOpts {
    * 设置为 false 时，即使有 lint 错误也继续构建（仅在 dev 模式下推荐）
    */
   failOnError?: boolean
-  restrictedImports?: Array<RawRestricted>
+  restrictedImports?: Array<RawRestrictedImport>
+  /** 按模式（glob / 正则）限制的导入 */
+  restrictedImportPatterns?: Array<RawRestrictedImportPattern>
+  /** 只对部分文件生效的导入限制 */
+  restrictedImportScopes?: Array<RawRestrictedImportScope>
   restrictedGlobals?: Array<RawRestricted>
   globals?: Record<string, boolean>
   environments?: RawEnvironment
   configFilePath?: string
+  /**
+   * 自动修复模式，默认为 none
+   * 开启后会把修复结果直接写回源文件（不会修改 node_modules 下的文件）
+   * 只在首次编译时修复，watch 模式下重新编译时只报告，避免写回源文件再次触发编译
+   */
+  fix?: 'none' | 'safe' | 'suggestions' | 'dangerous'
+  /** 输出机器可读的 lint 报告，用于 CI 产物 */
+  reports?: Array<RawLintReport>
+  /**
+   * 是否把 lint 结果持久化到 outputDir，下次启动时复用未修改文件的结果，默认为 false
+   * 配置或 oxlint 版本变化时缓存会整体失效；开启 import 插件时不使用缓存（跨模块规则的结果依赖其它文件）
+   */
+  cache?: boolean
+  /** 首次全量 lint 时只检查变更的文件（用于 PR 的 CI），不配置时检查所有文件 */
+  changedFiles?: RawChangedFilesOpts
+  /** 需要 lint 的文件 glob（相对 rspack 的 context），不配置时检查所有 js/ts 文件以及 vue/svelte/astro 单文件组件中的脚本 */
+  include?: Array<string>
+  /**
+   * 额外排除的文件 glob（相对 rspack 的 context）
+   * 内置排除 node_modules、dist、build 等目录，同时遵循 .gitignore、.oxlintignore 和配置中的 ignorePatterns
+   */
+  exclude?: Array<string>
+  /** 只 lint 出现在模块图中的模块，不遍历 rspack context 下的所有文件，默认为 false */
+  reachableOnly?: boolean
+  /** 已 lint 但没有被任何模块引用的文件报告的输出路径（相对 rspack 的 context），只在 reachableOnly 为 false 时生效 */
+  unreachableReport?: string
+  /**
+   * 叠加在内置规则集上的预设，按顺序合并，默认只使用内置规则集（recommended）
+   * 只对内置配置生效，配置了 configFilePath 时可以在自定义配置中 extends 生成的内置配置
+   */
+  presets?: Array<'recommended' | 'strict' | 'react' | 'typescript'>
+  /** 规则分类的严重级别覆盖，如 { correctness: 'error' } */
+  categories?: Record<string, 'off' | 'warn' | 'error'>
+  /**
+   * 单条规则的覆盖，如 { 'no-debugger': 'error' }，优先级最高
+   * 只给出严重级别时，保留内置配置中该规则的选项
+   */
+  rules?: Record<string, 'off' | 'warn' | 'error' | 0 | 1 | 2 | any[]>
+  /**
+   * 需要 lint 内联 <script> 的 HTML/EJS 模板 glob（相对 rspack 的 context），如 ['public/index.html']
+   * 带 src 的外部脚本和非 JavaScript 的 type 会被跳过，脚本中的 EJS 标签替换为等长的占位符后再 lint（包含 EJS 标签的模板不自动修复）
+   */
+  templates?: Array<string>
+  /** 允许的最大警告数（不包括 baseline 中已有的警告），超过时构建失败 */
+  maxWarnings?: number
+  /**
+   * baseline 文件名（如 '.oxlint-baseline.json'），配置后只报告 baseline 之外新增的违规
+   * baseline 文件按目录生效，从被 lint 的文件所在目录向上查找（不超过 rspack 的 context）最近的 baseline 文件
+   */
+  baseline?: string
+  /**
+   * 是否用首次编译的 lint 结果更新 baseline，默认为 false
+   * 只更新 lint 过的文件的记录，其它文件的记录保留（已删除的文件除外）
+   */
+  updateBaseline?: boolean
+  /**
+   * 未使用的 eslint-disable / oxlint-disable 指令的报告级别，默认为 error
+   * 报告为单独的 oxlint(unused-disable-directive) 诊断
+   */
+  unusedDirectives?: 'off' | 'warn' | 'error'
+  /**
+   * 项目使用的框架，影响 linter 的框架提示和默认开启的插件
+   * 不配置时根据项目根目录（rspack 的 context）下 package.json 的 dependencies / devDependencies / peerDependencies 自动检测
+   */
+  frameworks?: Array<'react' | 'vue' | 'next' | 'jest' | 'vitest'>
+  /**
+   * 开启的 oxlint 插件，不配置时使用 eslint、typescript、unicorn、oxc、import 加上框架对应的插件
+   * （react -> react，next -> react + nextjs，jest -> jest，vitest -> vitest）
+   */
+  plugins?: Array<'eslint' | 'typescript' | 'unicorn' | 'react' | 'oxc' | 'import' | 'jsx-a11y' | 'jest' | 'vitest' | 'promise' | 'node' | 'nextjs'>
+  /**
+   * 是否查找嵌套配置（项目根目录下各子目录中的 .oxlintrc.json），用于 monorepo 中各个包使用不同的规则，默认为 false
+   * 每个文件使用离它最近的嵌套配置，嵌套配置合并在插件的有效配置之上，跳过 node_modules、.gitignore 中的目录和 outputDir
+   * 新增的嵌套配置需要重启才能生效
+   */
+  nestedConfigs?: boolean
+  /**
+   * 是否收集 lint 统计（规则命中次数、文件耗时、累计耗时），默认为 false
+   * 开启后每轮编译输出汇总表格，并附加到 onLinted 的参数中
+   * 不包括单条规则的耗时（oxlint 没有暴露规则维度的耗时），规则只统计命中次数
+   */
+  stats?: boolean
+  /**
+   * 是否把 lint 消息转换为适合 dev server overlay 展示的诊断，默认为 false
+   * 消息为不带 ANSI 颜色的纯文本（文件位置、code frame、规则文档链接），热更新时未变化的警告只报告位置和消息，不再重复展示 code frame
+   */
+  overlay?: boolean
+  /** 每轮编译 lint 完成后的回调，参数为每个文件实际报告的 lint 消息（已过滤 baseline）和总数 */
+  onLinted?: (response: JsOxlintPluginResp) => void|Promise<void>
 }
 
 export interface RawRestricted {
@@ -171,6 +333,33 @@ export interface RawRestricted {
   message: string
 }
 
+export interface RawRestrictedImport {
+  name: string
+  message?: string
+  importNames?: Array<string>
+  allowImportNames?: Array<string>
+  allowTypeImports?: boolean
+}
+
+export interface RawRestrictedImportPattern {
+  group?: Array<string>
+  regex?: string
+  message?: string
+  importNames?: Array<string>
+  allowImportNames?: Array<string>
+  importNamePattern?: string
+  allowImportNamePattern?: string
+  caseSensitive?: boolean
+  allowTypeImports?: boolean
+}
+
+export interface RawRestrictedImportScope {
+  /** 生效的文件 glob，相对路径基于项目根目录（rspack 的 context） */
+  files: Array<string>
+  paths?: Array<RawRestrictedImport>
+  patterns?: Array<RawRestrictedImportPattern>
+}
+
 export interface RawUnifiedPluginOpts {
   /** oxlint-loader 的配置 */
   oxlint?: RawOxlintPluginOpts
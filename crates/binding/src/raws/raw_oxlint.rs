@@ -1,9 +1,15 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
+use derive_more::Debug;
 use napi::{Env, Unknown, bindgen_prelude::FromNapiValue};
 use napi_derive::napi;
 use rspack_core::BoxPlugin;
-use spack_plugin_oxlint::{OxlintPlugin, OxlintPluginOpts};
+use rspack_napi::threadsafe_function::ThreadsafeFunction;
+use spack_macros::ThreadsafeCallback;
+use spack_plugin_oxlint::{
+  FileDuration, FileReport, LintMessage, LintSeverity, LintStatsSummary, OxlintHookOpts,
+  OxlintPlugin, OxlintPluginOpts, OxlintPluginResp, RuleHits,
+};
 
 #[derive(Debug)]
 #[napi(object, object_to_js = false)]
@@ -21,7 +27,15 @@ pub struct RawOxlintPluginOpts {
   pub fail_on_error: Option<bool>,
 
   #[napi(js_name = "restrictedImports")]
-  pub restricted_imports: Option<Vec<RawRestricted>>,
+  pub restricted_imports: Option<Vec<RawRestrictedImport>>,
+
+  /// 按模式（glob / 正则）限制的导入
+  #[napi(js_name = "restrictedImportPatterns")]
+  pub restricted_import_patterns: Option<Vec<RawRestrictedImportPattern>>,
+
+  /// 只对部分文件生效的导入限制
+  #[napi(js_name = "restrictedImportScopes")]
+  pub restricted_import_scopes: Option<Vec<RawRestrictedImportScope>>,
 
   #[napi(js_name = "restrictedGlobals")]
   pub restricted_globals: Option<Vec<RawRestricted>>,
@@ -34,6 +48,304 @@ pub struct RawOxlintPluginOpts {
 
   #[napi(js_name = "configFilePath")]
   pub config_file_path: Option<String>,
+
+  /// 自动修复模式，默认为 none
+  /// 开启后会把修复结果直接写回源文件（不会修改 node_modules 下的文件）
+  /// 只在首次编译时修复，watch 模式下重新编译时只报告，避免写回源文件再次触发编译
+  #[napi(
+    js_name = "fix",
+    ts_type = "'none' | 'safe' | 'suggestions' | 'dangerous'"
+  )]
+  pub fix: Option<String>,
+
+  /// 输出机器可读的 lint 报告，用于 CI 产物
+  #[napi(js_name = "reports")]
+  pub reports: Option<Vec<RawLintReport>>,
+
+  /// 是否把 lint 结果持久化到 outputDir，下次启动时复用未修改文件的结果，默认为 false
+  /// 配置或 oxlint 版本变化时缓存会整体失效；开启 import 插件时不使用缓存（跨模块规则的结果依赖其它文件）
+  #[napi(js_name = "cache")]
+  pub cache: Option<bool>,
+
+  /// 首次全量 lint 时只检查变更的文件（用于 PR 的 CI），不配置时检查所有文件
+  #[napi(js_name = "changedFiles")]
+  pub changed_files: Option<RawChangedFilesOpts>,
+
+  /// 需要 lint 的文件 glob（相对 rspack 的 context），不配置时检查所有 js/ts 文件以及 vue/svelte/astro 单文件组件中的脚本
+  #[napi(js_name = "include")]
+  pub include: Option<Vec<String>>,
+
+  /// 额外排除的文件 glob（相对 rspack 的 context）
+  /// 内置排除 node_modules、dist、build 等目录，同时遵循 .gitignore、.oxlintignore 和配置中的 ignorePatterns
+  #[napi(js_name = "exclude")]
+  pub exclude: Option<Vec<String>>,
+
+  /// 只 lint 出现在模块图中的模块，不遍历 rspack context 下的所有文件，默认为 false
+  #[napi(js_name = "reachableOnly")]
+  pub reachable_only: Option<bool>,
+
+  /// 已 lint 但没有被任何模块引用的文件报告的输出路径（相对 rspack 的 context），只在 reachableOnly 为 false 时生效
+  #[napi(js_name = "unreachableReport")]
+  pub unreachable_report: Option<String>,
+
+  /// 叠加在内置规则集上的预设，按顺序合并，默认只使用内置规则集（recommended）
+  /// 只对内置配置生效，配置了 configFilePath 时可以在自定义配置中 extends 生成的内置配置
+  #[napi(
+    js_name = "presets",
+    ts_type = "Array<'recommended' | 'strict' | 'react' | 'typescript'>"
+  )]
+  pub presets: Option<Vec<String>>,
+
+  /// 规则分类的严重级别覆盖，如 { correctness: 'error' }
+  #[napi(
+    js_name = "categories",
+    ts_type = "Record<string, 'off' | 'warn' | 'error'>"
+  )]
+  pub categories: Option<HashMap<String, String>>,
+
+  /// 单条规则的覆盖，如 { 'no-debugger': 'error' }，优先级最高
+  /// 只给出严重级别时，保留内置配置中该规则的选项
+  #[napi(
+    js_name = "rules",
+    ts_type = "Record<string, 'off' | 'warn' | 'error' | 0 | 1 | 2 | any[]>"
+  )]
+  pub rules: Option<HashMap<String, serde_json::Value>>,
+
+  /// 需要 lint 内联 <script> 的 HTML/EJS 模板 glob（相对 rspack 的 context），如 ['public/index.html']
+  /// 带 src 的外部脚本和非 JavaScript 的 type 会被跳过，脚本中的 EJS 标签替换为等长的占位符后再 lint（包含 EJS 标签的模板不自动修复）
+  #[napi(js_name = "templates")]
+  pub templates: Option<Vec<String>>,
+
+  /// 允许的最大警告数（不包括 baseline 中已有的警告），超过时构建失败
+  #[napi(js_name = "maxWarnings")]
+  pub max_warnings: Option<u32>,
+
+  /// baseline 文件名（如 '.oxlint-baseline.json'），配置后只报告 baseline 之外新增的违规
+  /// baseline 文件按目录生效，从被 lint 的文件所在目录向上查找（不超过 rspack 的 context）最近的 baseline 文件
+  #[napi(js_name = "baseline")]
+  pub baseline: Option<String>,
+
+  /// 是否用首次编译的 lint 结果更新 baseline，默认为 false
+  /// 只更新 lint 过的文件的记录，其它文件的记录保留（已删除的文件除外）
+  #[napi(js_name = "updateBaseline")]
+  pub update_baseline: Option<bool>,
+
+  /// 未使用的 eslint-disable / oxlint-disable 指令的报告级别，默认为 error
+  /// 报告为单独的 oxlint(unused-disable-directive) 诊断
+  #[napi(js_name = "unusedDirectives", ts_type = "'off' | 'warn' | 'error'")]
+  pub unused_directives: Option<String>,
+
+  /// 项目使用的框架，影响 linter 的框架提示和默认开启的插件
+  /// 不配置时根据项目根目录（rspack 的 context）下 package.json 的 dependencies / devDependencies / peerDependencies 自动检测
+  #[napi(
+    js_name = "frameworks",
+    ts_type = "Array<'react' | 'vue' | 'next' | 'jest' | 'vitest'>"
+  )]
+  pub frameworks: Option<Vec<String>>,
+
+  /// 开启的 oxlint 插件，不配置时使用 eslint、typescript、unicorn、oxc、import 加上框架对应的插件
+  /// （react -> react，next -> react + nextjs，jest -> jest，vitest -> vitest）
+  #[napi(
+    js_name = "plugins",
+    ts_type = "Array<'eslint' | 'typescript' | 'unicorn' | 'react' | 'oxc' | 'import' | 'jsx-a11y' | 'jest' | 'vitest' | 'promise' | 'node' | 'nextjs'>"
+  )]
+  pub plugins: Option<Vec<String>>,
+
+  /// 是否查找嵌套配置（项目根目录下各子目录中的 .oxlintrc.json），用于 monorepo 中各个包使用不同的规则，默认为 false
+  /// 每个文件使用离它最近的嵌套配置，嵌套配置合并在插件的有效配置之上，跳过 node_modules、.gitignore 中的目录和 outputDir
+  /// 新增的嵌套配置需要重启才能生效
+  #[napi(js_name = "nestedConfigs")]
+  pub nested_configs: Option<bool>,
+
+  /// 是否收集 lint 统计（规则命中次数、文件耗时、累计耗时），默认为 false
+  /// 开启后每轮编译输出汇总表格，并附加到 onLinted 的参数中
+  /// 不包括单条规则的耗时（oxlint 没有暴露规则维度的耗时），规则只统计命中次数
+  #[napi(js_name = "stats")]
+  pub stats: Option<bool>,
+
+  /// 是否把 lint 消息转换为适合 dev server overlay 展示的诊断，默认为 false
+  /// 消息为不带 ANSI 颜色的纯文本（文件位置、code frame、规则文档链接），热更新时未变化的警告只报告位置和消息，不再重复展示 code frame
+  #[napi(js_name = "overlay")]
+  pub overlay: Option<bool>,
+
+  /// 每轮编译 lint 完成后的回调，参数为每个文件实际报告的 lint 消息（已过滤 baseline）和总数
+  #[napi(
+    js_name = "onLinted",
+    ts_type = "(response: JsOxlintPluginResp) => void|Promise<void>"
+  )]
+  #[debug(skip)]
+  pub on_linted: Option<ThreadsafeFunction<JsOxlintPluginResp, ()>>,
+}
+
+/// 插件的回调选项，通过 ThreadsafeCallback 派生宏转换（其余选项需要校验和默认值，在 TryFrom 中手动转换）
+#[derive(Debug, ThreadsafeCallback)]
+pub struct RawOxlintHookOpts {
+  #[debug(skip)]
+  #[threadsafe_callback]
+  pub on_linted: Option<ThreadsafeFunction<JsOxlintPluginResp, ()>>,
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct JsLintMessage {
+  /// 规则名，如 eslint/no-debugger
+  pub rule_id: Option<String>,
+  #[napi(ts_type = "'error' | 'warning'")]
+  pub severity: String,
+  pub message: String,
+  pub help: Option<String>,
+  /// 起始偏移量（字节）
+  pub start: u32,
+  /// 结束偏移量（字节）
+  pub end: u32,
+  /// 起始行号（从 1 开始）
+  pub line: u32,
+  /// 起始列号（从 1 开始）
+  pub column: u32,
+  pub end_line: u32,
+  pub end_column: u32,
+  /// 是否有可用的自动修复
+  pub fixable: bool,
+}
+
+impl From<LintMessage> for JsLintMessage {
+  fn from(value: LintMessage) -> Self {
+    let severity = match value.severity {
+      LintSeverity::Error => "error",
+      LintSeverity::Warning => "warning",
+    };
+
+    Self {
+      rule_id: value.rule_id,
+      severity: severity.to_string(),
+      message: value.message,
+      help: value.help,
+      start: value.start as u32,
+      end: value.end as u32,
+      line: value.line as u32,
+      column: value.column as u32,
+      end_line: value.end_line as u32,
+      end_column: value.end_column as u32,
+      fixable: value.fixable,
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct JsLintedFile {
+  pub file_path: String,
+  pub messages: Vec<JsLintMessage>,
+}
+
+impl From<FileReport> for JsLintedFile {
+  fn from(value: FileReport) -> Self {
+    Self {
+      file_path: value.file_path,
+      messages: value.messages.into_iter().map(|m| m.into()).collect(),
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct JsFileDuration {
+  pub file_path: String,
+  pub duration_ms: f64,
+}
+
+impl From<FileDuration> for JsFileDuration {
+  fn from(value: FileDuration) -> Self {
+    Self {
+      file_path: value.file_path,
+      duration_ms: value.duration_ms,
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct JsRuleHits {
+  pub rule_id: String,
+  pub count: u32,
+}
+
+impl From<RuleHits> for JsRuleHits {
+  fn from(value: RuleHits) -> Self {
+    Self {
+      rule_id: value.rule_id,
+      count: value.count as u32,
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct JsLintStatsSummary {
+  /// 所有 lint（首次 + 增量）的累计耗时
+  pub total_duration_ms: f64,
+  /// 累计 lint 次数（同一文件多次 lint 计多次）
+  pub lint_count: u32,
+  /// 命中持久化缓存的次数
+  pub cache_hits: u32,
+  /// 最近一次 lint 耗时最长的文件（降序）
+  pub slowest_files: Vec<JsFileDuration>,
+  /// 当前结果中每条规则的命中次数（降序），不包括规则的耗时
+  pub rules: Vec<JsRuleHits>,
+}
+
+impl From<LintStatsSummary> for JsLintStatsSummary {
+  fn from(value: LintStatsSummary) -> Self {
+    Self {
+      total_duration_ms: value.total_duration_ms,
+      lint_count: value.lint_count as u32,
+      cache_hits: value.cache_hits as u32,
+      slowest_files: value.slowest_files.into_iter().map(|f| f.into()).collect(),
+      rules: value.rules.into_iter().map(|r| r.into()).collect(),
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct JsOxlintPluginResp {
+  pub files: Vec<JsLintedFile>,
+  pub error_count: u32,
+  pub warning_count: u32,
+  /// lint 统计汇总（开启 stats 时）
+  pub stats: Option<JsLintStatsSummary>,
+}
+
+impl From<OxlintPluginResp> for JsOxlintPluginResp {
+  fn from(value: OxlintPluginResp) -> Self {
+    Self {
+      files: value.files.into_iter().map(|f| f.into()).collect(),
+      error_count: value.error_count as u32,
+      warning_count: value.warning_count as u32,
+      stats: value.stats.map(|stats| stats.into()),
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawChangedFilesOpts {
+  /// git 对比基准（分支、tag 或 commit），与 HEAD 求 merge-base 后取差异文件，默认为 main
+  #[napi(js_name = "base")]
+  pub base: Option<String>,
+
+  /// 直接指定需要 lint 的文件列表（相对路径基于 rspack 的 context），优先级高于 base
+  #[napi(js_name = "files")]
+  pub files: Option<Vec<String>>,
+}
+
+impl From<RawChangedFilesOpts> for spack_plugin_oxlint::ChangedFilesOpts {
+  fn from(value: RawChangedFilesOpts) -> Self {
+    Self {
+      base: value.base,
+      files: value.files,
+    }
+  }
 }
 
 #[derive(Debug)]
@@ -85,8 +397,156 @@ impl From<RawRestricted> for spack_plugin_oxlint::Restricted {
   }
 }
 
-impl From<RawOxlintPluginOpts> for OxlintPluginOpts {
-  fn from(value: RawOxlintPluginOpts) -> Self {
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawRestrictedImport {
+  #[napi(js_name = "name")]
+  pub name: String,
+
+  #[napi(js_name = "message")]
+  pub message: Option<String>,
+
+  #[napi(js_name = "importNames")]
+  pub import_names: Option<Vec<String>>,
+
+  #[napi(js_name = "allowImportNames")]
+  pub allow_import_names: Option<Vec<String>>,
+
+  #[napi(js_name = "allowTypeImports")]
+  pub allow_type_imports: Option<bool>,
+}
+
+impl From<RawRestrictedImport> for spack_plugin_oxlint::RestrictedImport {
+  fn from(value: RawRestrictedImport) -> Self {
+    Self {
+      name: value.name,
+      message: value.message,
+      import_names: value.import_names.unwrap_or_default(),
+      allow_import_names: value.allow_import_names.unwrap_or_default(),
+      allow_type_imports: value.allow_type_imports.unwrap_or(false),
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawRestrictedImportPattern {
+  #[napi(js_name = "group")]
+  pub group: Option<Vec<String>>,
+
+  #[napi(js_name = "regex")]
+  pub regex: Option<String>,
+
+  #[napi(js_name = "message")]
+  pub message: Option<String>,
+
+  #[napi(js_name = "importNames")]
+  pub import_names: Option<Vec<String>>,
+
+  #[napi(js_name = "allowImportNames")]
+  pub allow_import_names: Option<Vec<String>>,
+
+  #[napi(js_name = "importNamePattern")]
+  pub import_name_pattern: Option<String>,
+
+  #[napi(js_name = "allowImportNamePattern")]
+  pub allow_import_name_pattern: Option<String>,
+
+  #[napi(js_name = "caseSensitive")]
+  pub case_sensitive: Option<bool>,
+
+  #[napi(js_name = "allowTypeImports")]
+  pub allow_type_imports: Option<bool>,
+}
+
+impl From<RawRestrictedImportPattern> for spack_plugin_oxlint::RestrictedImportPattern {
+  fn from(value: RawRestrictedImportPattern) -> Self {
+    Self {
+      group: value.group.unwrap_or_default(),
+      regex: value.regex,
+      message: value.message,
+      import_names: value.import_names.unwrap_or_default(),
+      allow_import_names: value.allow_import_names.unwrap_or_default(),
+      import_name_pattern: value.import_name_pattern,
+      allow_import_name_pattern: value.allow_import_name_pattern,
+      case_sensitive: value.case_sensitive.unwrap_or(false),
+      allow_type_imports: value.allow_type_imports.unwrap_or(false),
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawRestrictedImportScope {
+  /// 生效的文件 glob，相对路径基于项目根目录（rspack 的 context）
+  #[napi(js_name = "files")]
+  pub files: Vec<String>,
+
+  #[napi(js_name = "paths")]
+  pub paths: Option<Vec<RawRestrictedImport>>,
+
+  #[napi(js_name = "patterns")]
+  pub patterns: Option<Vec<RawRestrictedImportPattern>>,
+}
+
+impl From<RawRestrictedImportScope> for spack_plugin_oxlint::RestrictedImportScope {
+  fn from(value: RawRestrictedImportScope) -> Self {
+    Self {
+      files: value.files,
+      paths: value
+        .paths
+        .unwrap_or_default()
+        .into_iter()
+        .map(From::from)
+        .collect(),
+      patterns: value
+        .patterns
+        .unwrap_or_default()
+        .into_iter()
+        .map(From::from)
+        .collect(),
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawLintReport {
+  #[napi(
+    js_name = "format",
+    ts_type = "'json' | 'sarif' | 'junit' | 'checkstyle'"
+  )]
+  pub format: String,
+
+  /// 报告文件路径，相对路径基于 rspack 的 context
+  #[napi(js_name = "outputPath")]
+  pub output_path: String,
+}
+
+impl TryFrom<RawLintReport> for spack_plugin_oxlint::LintReport {
+  type Error = napi::Error;
+
+  fn try_from(value: RawLintReport) -> napi::Result<Self> {
+    let format = value.format.parse().map_err(|_| {
+      napi::Error::from_reason(format!("Unknown oxlint report format: {}", value.format))
+    })?;
+
+    Ok(Self {
+      format,
+      output_path: value.output_path,
+    })
+  }
+}
+
+impl TryFrom<RawOxlintPluginOpts> for OxlintPluginOpts {
+  type Error = napi::Error;
+
+  fn try_from(value: RawOxlintPluginOpts) -> napi::Result<Self> {
+    let hooks: OxlintHookOpts = RawOxlintHookOpts {
+      on_linted: value.on_linted,
+    }
+    .into();
+
     let environments = value
       .environments
       .map(|e| e.into())
@@ -106,6 +566,20 @@ impl From<RawOxlintPluginOpts> for OxlintPluginOpts {
       .map(From::from)
       .collect();
 
+    let restricted_import_patterns = value
+      .restricted_import_patterns
+      .unwrap_or_default()
+      .into_iter()
+      .map(From::from)
+      .collect();
+
+    let restricted_import_scopes = value
+      .restricted_import_scopes
+      .unwrap_or_default()
+      .into_iter()
+      .map(From::from)
+      .collect();
+
     let restricted_globals = value
       .restricted_globals
       .unwrap_or_default()
@@ -117,21 +591,127 @@ impl From<RawOxlintPluginOpts> for OxlintPluginOpts {
 
     let config_file_path = value.config_file_path;
 
-    Self {
+    let fix = value
+      .fix
+      .map(|fix| {
+        fix
+          .parse()
+          .map_err(|_| napi::Error::from_reason(format!("Unknown oxlint fix mode: {}", fix)))
+      })
+      .transpose()?
+      .unwrap_or_default();
+
+    let reports = value
+      .reports
+      .unwrap_or_default()
+      .into_iter()
+      .map(TryFrom::try_from)
+      .collect::<napi::Result<_>>()?;
+
+    let cache = value.cache.unwrap_or(false);
+
+    let changed_files = value.changed_files.map(From::from);
+
+    let include = value.include.unwrap_or_default();
+
+    let exclude = value.exclude.unwrap_or_default();
+
+    let reachable_only = value.reachable_only.unwrap_or(false);
+
+    let presets = value
+      .presets
+      .unwrap_or_default()
+      .into_iter()
+      .map(|preset| {
+        preset
+          .parse()
+          .map_err(|_| napi::Error::from_reason(format!("Unknown oxlint preset: {}", preset)))
+      })
+      .collect::<napi::Result<_>>()?;
+
+    let categories = value
+      .categories
+      .unwrap_or_default()
+      .into_iter()
+      .map(|(category, severity)| (category, serde_json::Value::String(severity)))
+      .collect();
+
+    let rules = value.rules.unwrap_or_default();
+
+    let frameworks = value
+      .frameworks
+      .map(|frameworks| {
+        frameworks
+          .into_iter()
+          .map(|framework| {
+            framework.parse().map_err(|_| {
+              napi::Error::from_reason(format!("Unknown oxlint framework: {}", framework))
+            })
+          })
+          .collect::<napi::Result<_>>()
+      })
+      .transpose()?;
+
+    let unused_directives = value
+      .unused_directives
+      .map(|severity| {
+        severity.parse().map_err(|_| {
+          napi::Error::from_reason(format!("Unknown oxlint unusedDirectives: {}", severity))
+        })
+      })
+      .transpose()?
+      .unwrap_or_default();
+
+    let plugins = value
+      .plugins
+      .unwrap_or_default()
+      .into_iter()
+      .map(|plugin| {
+        plugin
+          .parse()
+          .map_err(|_| napi::Error::from_reason(format!("Unknown oxlint plugin: {}", plugin)))
+      })
+      .collect::<napi::Result<_>>()?;
+
+    Ok(Self {
       output_dir,
       show_warning,
       fail_on_error,
       restricted_imports,
+      restricted_import_patterns,
+      restricted_import_scopes,
       restricted_globals,
       globals,
       environments,
       config_file_path,
-    }
+      fix,
+      reports,
+      cache,
+      changed_files,
+      include,
+      exclude,
+      reachable_only,
+      unreachable_report: value.unreachable_report,
+      presets,
+      categories,
+      rules,
+      templates: value.templates.unwrap_or_default(),
+      max_warnings: value.max_warnings.map(|max| max as usize),
+      baseline: value.baseline,
+      update_baseline: value.update_baseline.unwrap_or(false),
+      unused_directives,
+      frameworks,
+      plugins,
+      nested_configs: value.nested_configs.unwrap_or(false),
+      stats: value.stats.unwrap_or(false),
+      overlay: value.overlay.unwrap_or(false),
+      on_linted: hooks.on_linted.map(Arc::from),
+    })
   }
 }
 
 #[allow(unused)]
 pub fn binding(_env: Env, options: Unknown<'_>) -> napi::Result<BoxPlugin> {
   let options = RawOxlintPluginOpts::from_unknown(options)?;
-  Ok(Box::new(OxlintPlugin::new(options.into())) as BoxPlugin)
+  Ok(Box::new(OxlintPlugin::new(options.try_into()?)) as BoxPlugin)
 }
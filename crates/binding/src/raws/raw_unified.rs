@@ -18,17 +18,19 @@ pub struct RawUnifiedPluginOpts {
   pub case_sensitive: Option<RawCaseSensitivePathsPluginOpts>,
 }
 
-impl From<RawUnifiedPluginOpts> for UnifiedPluginOpts {
-  fn from(value: RawUnifiedPluginOpts) -> Self {
-    Self {
-      oxlint: value.oxlint.map(From::from),
+impl TryFrom<RawUnifiedPluginOpts> for UnifiedPluginOpts {
+  type Error = napi::Error;
+
+  fn try_from(value: RawUnifiedPluginOpts) -> napi::Result<Self> {
+    Ok(Self {
+      oxlint: value.oxlint.map(TryFrom::try_from).transpose()?,
       case_sensitive: value.case_sensitive.map(From::from),
-    }
+    })
   }
 }
 
 #[allow(unused)]
 pub fn binding(_env: Env, options: Unknown<'_>) -> napi::Result<BoxPlugin> {
   let options = RawUnifiedPluginOpts::from_unknown(options)?;
-  Ok(Box::new(UnifiedPlugin::new(options.into())) as BoxPlugin)
+  Ok(Box::new(UnifiedPlugin::new(options.try_into()?)) as BoxPlugin)
 }
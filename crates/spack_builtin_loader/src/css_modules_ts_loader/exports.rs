@@ -0,0 +1,298 @@
+use std::collections::{BTreeMap, HashMap};
+
+use lightningcss::{
+  css_modules::{self, CssModuleReference},
+  printer::PrinterOptions,
+  properties::custom::{Token, TokenOrValue},
+  rules::{CssRule, unknown::UnknownAtRule},
+  stylesheet::{ParserOptions, StyleSheet},
+  traits::ToCss,
+};
+use rspack_error::{Result, ToStringResultToRspackResultExt};
+
+/// CSS Modules 的一个导出：局部作用域的类名、id、`@keyframes`，`@value` 定义或导入的值，以及 ICSS `:export { ... }` 块中声明的值
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CssExport {
+  /// `composes` 组合的类名，如 `base`、`btn from './button.css'`、`reset from global`
+  pub composes: Vec<String>,
+}
+
+/// 用 lightningcss 的 CSS Modules 解析获取模块的导出（按导出名排序）
+///
+/// 直接解析源文件，不依赖上游 loader 生成的 JS，`namedExport`、`esModule`、压缩输出和 rspack 原生 CSS Modules 都能得到相同的结果
+pub fn collect_css_exports(filename: &str, code: &str) -> Result<BTreeMap<String, CssExport>> {
+  let mut exports = collect_icss_exports(filename, code)?;
+
+  let options = ParserOptions {
+    filename: filename.to_string(),
+    css_modules: Some(css_modules::Config::default()),
+    // 预处理器语法、ICSS 的 `:import()` 等无法解析的规则跳过即可
+    error_recovery: true,
+    ..Default::default()
+  };
+
+  let stylesheet = StyleSheet::parse(code, options).to_rspack_result()?;
+
+  let result = stylesheet
+    .to_css(PrinterOptions::default())
+    .to_rspack_result_with_message(|e| format!("failed to generate css modules exports: {e}"))?;
+
+  let css_exports = result.exports.unwrap_or_default();
+
+  // `composes` 中本文件的类名是编译后的名字（如 `EgL3uq_base`），换回源码中的名字
+  let local_names = css_exports
+    .iter()
+    .map(|(name, export)| (export.name.as_str(), name.as_str()))
+    .collect::<HashMap<_, _>>();
+
+  for (name, export) in &css_exports {
+    let composes = export
+      .composes
+      .iter()
+      .map(|reference| match reference {
+        CssModuleReference::Local { name } => local_names
+          .get(name.as_str())
+          .map_or_else(|| name.clone(), |name| name.to_string()),
+        CssModuleReference::Global { name } => format!("{} from global", name),
+        CssModuleReference::Dependency { name, specifier } => {
+          format!("{} from '{}'", name, specifier)
+        }
+      })
+      .collect();
+
+    exports.insert(name.clone(), CssExport { composes });
+  }
+
+  Ok(exports)
+}
+
+/// 代码是否为 CSS：能按 CSS 无错误地解析（上游 loader 生成的 JS 无法解析）
+///
+/// loader 配置在 sass、less 等预处理器之后、css-loader 之前时，输入就是编译后的 CSS
+pub fn is_css(filename: &str, code: &str) -> bool {
+  let options = ParserOptions {
+    filename: filename.to_string(),
+    css_modules: Some(css_modules::Config::default()),
+    ..Default::default()
+  };
+
+  StyleSheet::parse(code, options).is_ok()
+}
+
+/// 从 css-loader 生成的 JS 中获取导出名（`exports.locals` / `___CSS_LOADER_EXPORT___.locals` 之后带引号的键）
+///
+/// 无法得到 CSS 源码（如 sass、less 模块且 loader 配置在 css-loader 之后）时的兜底，拿不到 `composes`
+pub fn collect_js_exports(code: &str) -> BTreeMap<String, CssExport> {
+  let locals = code
+    .split("exports.locals")
+    .nth(1)
+    .or_else(|| code.split("___CSS_LOADER_EXPORT___.locals").nth(1))
+    .unwrap_or_default();
+
+  let mut exports = BTreeMap::new();
+  let mut chars = locals.char_indices().peekable();
+
+  while let Some((start, c)) = chars.next() {
+    if c != '"' {
+      continue;
+    }
+
+    // 读取到未转义的结束引号
+    let mut end = None;
+    while let Some((i, c)) = chars.next() {
+      match c {
+        '\\' => {
+          chars.next();
+        }
+        '"' => {
+          end = Some(i);
+          break;
+        }
+        _ => {}
+      }
+    }
+
+    let Some(end) = end else {
+      break;
+    };
+
+    // 后面紧跟 `:` 的字符串才是键
+    if chars.peek().is_some_and(|(_, c)| *c == ':') {
+      exports.insert(locals[start + 1..end].to_string(), CssExport::default());
+    }
+  }
+
+  exports
+}
+
+/// 获取 `@value` 和 ICSS `:export { ... }` 块的导出
+///
+/// 开启 CSS Modules 时 lightningcss 会丢弃 `@value` 规则，所以单独按普通 CSS 解析一次
+fn collect_icss_exports(filename: &str, code: &str) -> Result<BTreeMap<String, CssExport>> {
+  let options = ParserOptions {
+    filename: filename.to_string(),
+    error_recovery: true,
+    ..Default::default()
+  };
+
+  let stylesheet = StyleSheet::parse(code, options).to_rspack_result()?;
+
+  let mut exports = BTreeMap::new();
+
+  for rule in &stylesheet.rules.0 {
+    match rule {
+      CssRule::Unknown(rule) if rule.name.as_ref() == "value" => {
+        for name in value_names(rule) {
+          exports.insert(name, CssExport::default());
+        }
+      }
+      CssRule::Style(rule) => {
+        let is_icss_export = rule
+          .selectors
+          .to_css_string(PrinterOptions::default())
+          .is_ok_and(|selector| selector == ":export");
+
+        if !is_icss_export {
+          continue;
+        }
+
+        for property in rule
+          .declarations
+          .declarations
+          .iter()
+          .chain(rule.declarations.important_declarations.iter())
+        {
+          exports.insert(
+            property.property_id().name().to_string(),
+            CssExport::default(),
+          );
+        }
+      }
+      _ => {}
+    }
+  }
+
+  Ok(exports)
+}
+
+/// 获取 `@value` 规则导出的名字
+///
+/// - `@value primary: #3498db;` => `primary`
+/// - `@value primary, secondary as accent from './theme.css';` => `primary`, `accent`
+fn value_names(rule: &UnknownAtRule) -> Vec<String> {
+  let mut names = vec![];
+  let mut is_alias = false;
+
+  for token in &rule.prelude.0 {
+    match token {
+      TokenOrValue::Token(Token::Ident(ident)) => match ident.as_ref() {
+        "from" if !names.is_empty() => break,
+        "as" if !names.is_empty() => is_alias = true,
+        ident => {
+          if is_alias {
+            names.pop();
+            is_alias = false;
+          }
+          names.push(ident.to_string());
+        }
+      },
+      TokenOrValue::Token(Token::WhiteSpace(_) | Token::Comma) => {}
+      _ => break,
+    }
+  }
+
+  names
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn export_names(code: &str) -> Vec<String> {
+    collect_css_exports("test.module.css", code)
+      .unwrap()
+      .into_keys()
+      .collect()
+  }
+
+  #[test]
+  fn test_local_exports() {
+    assert_eq!(
+      export_names(".btn { color: red } #header {} @keyframes fade {} div.active > .icon {}"),
+      vec!["active", "btn", "fade", "header", "icon"]
+    );
+  }
+
+  #[test]
+  fn test_composes() {
+    let exports = collect_css_exports(
+      "test.module.css",
+      r#"
+        .base {}
+        .btn {
+          composes: base;
+          composes: reset from global;
+          composes: primary from './button.css';
+        }
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(exports["base"], CssExport::default());
+    assert_eq!(
+      exports["btn"].composes,
+      vec!["base", "reset from global", "primary from './button.css'"]
+    );
+  }
+
+  #[test]
+  fn test_value_exports() {
+    assert_eq!(
+      export_names(
+        r#"
+          @value primary: #3498db;
+          @value small, large as big from './sizes.css';
+          @value secondary from './theme.css';
+          .btn { color: primary }
+        "#
+      ),
+      vec!["big", "btn", "primary", "secondary", "small"]
+    );
+  }
+
+  #[test]
+  fn test_is_css() {
+    assert!(is_css(
+      "test.module.css",
+      ".btn { composes: base; } .base {}"
+    ));
+    assert!(!is_css(
+      "test.module.css",
+      "// Imports\nimport ___CSS_LOADER_API_IMPORT___ from \"css-loader/api.js\";\nexport default {};"
+    ));
+  }
+
+  #[test]
+  fn test_js_exports() {
+    let code = r#"___CSS_LOADER_EXPORT___.push([module.id, ".a_btn{}", ""]);
+___CSS_LOADER_EXPORT___.locals = {
+	"btn": "a_btn",
+	"foo-bar": "a_foo-bar a_base",
+	"say\"hi": "a_say"
+};"#;
+
+    assert_eq!(
+      collect_js_exports(code).into_keys().collect::<Vec<_>>(),
+      vec!["btn", "foo-bar", "say\\\"hi"]
+    );
+    assert!(collect_js_exports("module.exports = {};").is_empty());
+  }
+
+  #[test]
+  fn test_icss_exports() {
+    assert_eq!(
+      export_names(":export { primaryColor: #fff; spacing: 4px !important } .btn {}"),
+      vec!["btn", "primaryColor", "spacing"]
+    );
+  }
+}
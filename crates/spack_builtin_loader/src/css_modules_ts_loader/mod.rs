@@ -1,4 +1,7 @@
-use std::path::{Path, PathBuf};
+use std::{
+  collections::BTreeMap,
+  path::{Path, PathBuf},
+};
 
 use async_trait::async_trait;
 use rspack_cacheable::{cacheable, cacheable_dyn};
@@ -10,7 +13,18 @@ use serde::{Deserialize, Serialize};
 use strum_macros::EnumString;
 use tokio::fs;
 
-use crate::loader_cache::LoaderWithIdentifier;
+use crate::{
+  css_modules_ts_loader::{
+    exports::{CssExport, collect_css_exports, collect_js_exports, is_css},
+    naming::is_valid_identifier,
+  },
+  loader_cache::LoaderWithIdentifier,
+};
+
+pub(crate) mod exports;
+pub(crate) mod naming;
+
+pub use naming::ExportLocalsConvention;
 
 pub const CSS_MODULES_TS_LOADER_IDENTIFIER: &str = "builtin:css-modules-ts-loader";
 
@@ -25,8 +39,20 @@ pub enum Mode {
 
 #[cacheable]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct CssModulesTsLoaderOpts {
   pub mode: Mode,
+  /// 生成具名导出（`export const foo: string;`）而不是默认导出，与 css-loader 的 `modules.namedExport` 对应
+  ///
+  /// 不是合法标识符的名字（如 `foo-bar`、`default`）无法具名导出，会被跳过
+  #[serde(default)]
+  pub named_export: bool,
+  /// 导出名的转换方式，与 css-loader 的 `modules.exportLocalsConvention` 对应
+  #[serde(default)]
+  pub export_locals_convention: ExportLocalsConvention,
+  /// 默认导出的 `CssExports` 成员是否声明为 `readonly`
+  #[serde(default)]
+  pub readonly: bool,
 }
 
 #[cacheable]
@@ -79,16 +105,86 @@ impl CssModulesTsLoader {
     Ok(dts_file_name)
   }
 
-  pub fn extract_local_exports(&self, content: &str) -> String {
-    let mut local_exports = content.split("exports.locals").nth(1).unwrap_or("");
-    if local_exports.is_empty() {
-      local_exports = content
-        .split("___CSS_LOADER_EXPORT___.locals")
-        .nth(1)
-        .unwrap_or("");
+  /// 按 `export_locals_convention` 转换导出名
+  fn convert_exports(&self, exports: BTreeMap<String, CssExport>) -> BTreeMap<String, CssExport> {
+    exports
+      .into_iter()
+      .flat_map(|(name, export)| {
+        self
+          .options
+          .export_locals_convention
+          .convert(&name)
+          .into_iter()
+          .map(move |name| (name, export.clone()))
+      })
+      .collect()
+  }
+
+  fn render_typings(&self, exports: &BTreeMap<String, CssExport>) -> String {
+    // banner 中包含所有导出名（包括具名导出时被跳过的），verify 模式据此判断内容是否变化
+    let banner_str = exports.keys().cloned().collect::<Vec<_>>().join(",");
+
+    let composes_doc = |export: &CssExport, indent: &str| {
+      if export.composes.is_empty() {
+        String::new()
+      } else {
+        format!("{indent}/** composes: {} */\n", export.composes.join(", "))
+      }
+    };
+
+    if self.options.named_export {
+      let (names, skipped): (Vec<_>, Vec<_>) = exports
+        .iter()
+        .partition(|(key, _)| is_valid_identifier(key));
+
+      let mut dts_str = names
+        .into_iter()
+        .map(|(key, export)| format!("{}export const {key}: string;", composes_doc(export, "")))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+      if !skipped.is_empty() {
+        let skipped = skipped
+          .into_iter()
+          .map(|(key, _)| format!("'{key}'"))
+          .collect::<Vec<_>>()
+          .join(", ");
+        dts_str = format!("// Not valid identifiers, skipped: {skipped}\n{dts_str}");
+      }
+
+      return format!(
+        r#"// Please do not delete the comments, as they are used to determine content changes.
+// Banner: {banner_str}
+{dts_str}"#
+      );
     }
 
-    local_exports.to_string()
+    let readonly = if self.options.readonly {
+      "readonly "
+    } else {
+      ""
+    };
+
+    let dts_str = exports
+      .iter()
+      .map(|(key, export)| {
+        format!(
+          r##"{}  {readonly}'{key}':string;"##,
+          composes_doc(export, "  ")
+        )
+      })
+      .collect::<Vec<_>>()
+      .join("\n");
+
+    format!(
+      r#"// Please do not delete the comments, as they are used to determine content changes.
+// Banner: {banner_str}
+interface CssExports {{
+{dts_str}
+}}
+export const cssExports: CssExports;
+export default cssExports;"#
+    )
   }
 
   fn extract_banner_keys(&self, content: &str) -> FxHashSet<String> {
@@ -113,47 +209,45 @@ impl Loader<RunnerContext> for CssModulesTsLoader {
 
     let source_map = loader_context.take_source_map();
 
-    let source_str = source
-      .clone()
-      .map(|v| v.into_string_lossy().to_string())
-      .unwrap();
-
-    let resource = loader_context.resource();
+    let Some(resource_path) = loader_context.resource_path() else {
+      loader_context.finish_with((source, source_map));
+      return Ok(());
+    };
 
-    let dts_file_name = self.filename_to_typings_filename(resource)?;
+    let filename = resource_path.as_str().to_string();
+    let is_css_file = resource_path.extension() == Some("css");
 
-    let mut css_module_keys = FxHashSet::default();
+    let dts_file_name = self.filename_to_typings_filename(loader_context.resource())?;
 
-    let key_regex = regex::Regex::new(r#""([^"\\]*(?:\\.[^"\\]*)*)":"#)
-      .map_err(|e| rspack_error::Error::error(format!("Failed to compile regex: {}", e)))?;
+    let source_str = source
+      .clone()
+      .map(|v| v.into_string_lossy().to_string())
+      .unwrap_or_default();
 
-    let local_exports = self.extract_local_exports(&source_str);
+    // 优先解析 CSS 而不是上游 loader 生成的 JS，导出名与上游 loader 的输出格式无关：
+    // 1. 输入就是 CSS（loader 在 css-loader 之前，sass、less 等预处理器之后）：解析输入
+    // 2. 输入是 JS 且资源是 .css 文件：解析磁盘上的源文件
+    // 3. 其它（如 css-loader 之后的 sass、less 模块）：从上游 loader 生成的 JS 中获取导出名
+    let exports = if is_css(&filename, &source_str) {
+      collect_css_exports(&filename, &source_str)?
+    } else if is_css_file {
+      let css_source = fs::read_to_string(&filename).await?;
 
-    for cap in key_regex.captures_iter(&local_exports) {
-      if let Some(matched_key) = cap.get(1) {
-        css_module_keys.insert(matched_key.as_str().to_string());
-      }
-    }
+      // 源文件直接从磁盘读取，显式注册为依赖，保证修改后重新生成类型
+      loader_context
+        .file_dependencies
+        .insert(resource_path.as_std_path().to_path_buf());
 
-    let keys_vec: Vec<String> = css_module_keys.iter().cloned().collect();
+      collect_css_exports(&filename, &css_source)?
+    } else {
+      collect_js_exports(&source_str)
+    };
 
-    let banner_str = keys_vec.join(",");
+    let exports = self.convert_exports(exports);
 
-    let dts_str = css_module_keys
-      .iter()
-      .map(|key| format!(r##"  '{key}':string;"##))
-      .collect::<Vec<_>>()
-      .join("\n");
+    let css_module_keys = exports.keys().cloned().collect::<FxHashSet<_>>();
 
-    let dts_content = format!(
-      r#"// Please do not delete the comments, as they are used to determine content changes.
-// Banner: {banner_str}
-interface CssExports {{
-{dts_str}
-}}
-export const cssExports: CssExports;
-export default cssExports;"#
-    );
+    let dts_content = self.render_typings(&exports);
 
     if !matches!(self.options.mode, Mode::Verify) {
       fs::write(&dts_file_name, dts_content).await?;
@@ -192,3 +286,75 @@ export default cssExports;"#
     Ok(())
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn loader(named_export: bool, readonly: bool) -> CssModulesTsLoader {
+    CssModulesTsLoader::new(CssModulesTsLoaderOpts {
+      mode: Mode::Emit,
+      named_export,
+      export_locals_convention: ExportLocalsConvention::CamelCase,
+      readonly,
+    })
+  }
+
+  fn exports(names: &[&str]) -> BTreeMap<String, CssExport> {
+    names
+      .iter()
+      .map(|name| {
+        let export = CssExport {
+          composes: if *name == "btn" {
+            vec!["base".to_string()]
+          } else {
+            vec![]
+          },
+        };
+        (name.to_string(), export)
+      })
+      .collect()
+  }
+
+  #[test]
+  fn test_render_named_export() {
+    let loader = loader(true, false);
+    let exports = loader.convert_exports(exports(&["btn", "default", "foo-bar"]));
+
+    assert_eq!(
+      loader.render_typings(&exports),
+      r#"// Please do not delete the comments, as they are used to determine content changes.
+// Banner: btn,default,foo-bar,fooBar
+// Not valid identifiers, skipped: 'default', 'foo-bar'
+/** composes: base */
+export const btn: string;
+export const fooBar: string;"#
+    );
+  }
+
+  #[test]
+  fn test_render_readonly() {
+    let loader = loader(false, true);
+    let exports = loader.convert_exports(exports(&["btn", "foo-bar"]));
+
+    assert_eq!(
+      loader.render_typings(&exports),
+      r#"// Please do not delete the comments, as they are used to determine content changes.
+// Banner: btn,foo-bar,fooBar
+interface CssExports {
+  /** composes: base */
+  readonly 'btn':string;
+  readonly 'foo-bar':string;
+  readonly 'fooBar':string;
+}
+export const cssExports: CssExports;
+export default cssExports;"#
+    );
+
+    // banner 中的导出名用于 verify 模式的比较
+    assert_eq!(
+      loader.extract_banner_keys(&loader.render_typings(&exports)),
+      FxHashSet::from_iter(["btn", "foo-bar", "fooBar"].map(String::from))
+    );
+  }
+}
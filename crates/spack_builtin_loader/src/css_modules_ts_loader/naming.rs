@@ -0,0 +1,355 @@
+use rspack_cacheable::cacheable;
+use serde::{Deserialize, Serialize};
+
+/// 导出名的转换方式，与 css-loader 的 `modules.exportLocalsConvention` 一致
+#[cacheable]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExportLocalsConvention {
+  /// 保持原样
+  #[default]
+  #[serde(alias = "as-is")]
+  AsIs,
+  /// 原名 + 驼峰
+  #[serde(alias = "camel-case")]
+  CamelCase,
+  /// 只导出驼峰
+  #[serde(alias = "camel-case-only")]
+  CamelCaseOnly,
+  /// 原名 + 只转换 `-` 的驼峰
+  #[serde(alias = "dashes")]
+  Dashes,
+  /// 只导出只转换 `-` 的驼峰
+  #[serde(alias = "dashes-only")]
+  DashesOnly,
+}
+
+impl ExportLocalsConvention {
+  /// 获取一个导出名转换后的所有名字
+  pub fn convert(&self, name: &str) -> Vec<String> {
+    let mut names = match self {
+      Self::AsIs => vec![name.to_string()],
+      Self::CamelCase => vec![name.to_string(), camel_case(name)],
+      Self::CamelCaseOnly => vec![camel_case(name)],
+      Self::Dashes => vec![name.to_string(), dashes_camel_case(name)],
+      Self::DashesOnly => vec![dashes_camel_case(name)],
+    };
+
+    names.dedup();
+    names
+  }
+}
+
+fn is_separator(c: char) -> bool {
+  matches!(c, '_' | '.' | '-' | ' ')
+}
+
+/// 在原有的驼峰边界插入 `-`，避免转小写后丢失（`fooBar` => `foo-Bar`，`FOOBar` => `FOO-Bar`）
+fn preserve_camel_case(input: &str) -> String {
+  let mut chars = input.chars().collect::<Vec<_>>();
+
+  let mut is_last_char_lower = false;
+  let mut is_last_char_upper = false;
+  let mut is_last_last_char_upper = false;
+
+  let mut i = 0;
+  while i < chars.len() {
+    let c = chars[i];
+
+    if is_last_char_lower && c.is_uppercase() {
+      chars.insert(i, '-');
+      is_last_char_lower = false;
+      is_last_last_char_upper = is_last_char_upper;
+      is_last_char_upper = true;
+      i += 1;
+    } else if is_last_char_upper && is_last_last_char_upper && c.is_lowercase() {
+      chars.insert(i - 1, '-');
+      is_last_last_char_upper = is_last_char_upper;
+      is_last_char_upper = false;
+      is_last_char_lower = true;
+    } else {
+      is_last_char_lower = c.is_lowercase();
+      is_last_last_char_upper = is_last_char_upper;
+      is_last_char_upper = c.is_uppercase();
+    }
+
+    i += 1;
+  }
+
+  chars.into_iter().collect()
+}
+
+/// 与 css-loader 的 `camelCase` 一致：`foo-bar_baz` => `fooBarBaz`，`FOOBar` => `fooBar`
+pub fn camel_case(input: &str) -> String {
+  let input = input.trim();
+
+  match input.chars().count() {
+    0 => return String::new(),
+    1 => return input.to_lowercase(),
+    _ => {}
+  }
+
+  let input = if input != input.to_lowercase() {
+    preserve_camel_case(input)
+  } else {
+    input.to_string()
+  };
+
+  let chars = input
+    .trim_start_matches(is_separator)
+    .to_lowercase()
+    .chars()
+    .collect::<Vec<_>>();
+
+  let mut result = String::new();
+  let mut i = 0;
+
+  while i < chars.len() {
+    let c = chars[i];
+
+    if is_separator(c) {
+      let end = (i..chars.len())
+        .find(|&j| !is_separator(chars[j]))
+        .unwrap_or(chars.len());
+
+      match chars.get(end) {
+        // 分隔符后的字母、数字转大写，末尾的分隔符直接去掉
+        Some(next) if next.is_alphanumeric() => result.extend(next.to_uppercase()),
+        None => {}
+        Some(next) => {
+          result.extend(&chars[i..end]);
+          result.push(*next);
+        }
+      }
+
+      i = end + 1;
+      continue;
+    }
+
+    result.push(c);
+
+    // 数字后的字母转大写
+    if c.is_ascii_digit()
+      && let Some(next) = chars.get(i + 1)
+      && (next.is_alphabetic() || *next == '_')
+    {
+      result.extend(next.to_uppercase());
+      i += 2;
+      continue;
+    }
+
+    i += 1;
+  }
+
+  result
+}
+
+/// 与 css-loader 的 `dashesCamelCase` 一致，只转换 `-`：`foo-bar_baz` => `fooBar_baz`
+pub fn dashes_camel_case(input: &str) -> String {
+  let mut result = String::new();
+  let mut chars = input.chars().peekable();
+
+  while let Some(c) = chars.next() {
+    if c != '-' {
+      result.push(c);
+      continue;
+    }
+
+    let mut dashes = String::from('-');
+    while chars.peek() == Some(&'-') {
+      dashes.push('-');
+      chars.next();
+    }
+
+    match chars.peek() {
+      Some(next) if next.is_ascii_alphanumeric() || *next == '_' => {
+        result.extend(next.to_uppercase());
+        chars.next();
+      }
+      _ => result.push_str(&dashes),
+    }
+  }
+
+  result
+}
+
+/// JS 保留字，不能作为 `export const` 的名字
+const RESERVED_WORDS: &[&str] = &[
+  "arguments",
+  "await",
+  "break",
+  "case",
+  "catch",
+  "class",
+  "const",
+  "continue",
+  "debugger",
+  "default",
+  "delete",
+  "do",
+  "else",
+  "enum",
+  "eval",
+  "export",
+  "extends",
+  "false",
+  "finally",
+  "for",
+  "function",
+  "if",
+  "implements",
+  "import",
+  "in",
+  "instanceof",
+  "interface",
+  "let",
+  "new",
+  "null",
+  "package",
+  "private",
+  "protected",
+  "public",
+  "return",
+  "static",
+  "super",
+  "switch",
+  "this",
+  "throw",
+  "true",
+  "try",
+  "typeof",
+  "var",
+  "void",
+  "while",
+  "with",
+  "yield",
+];
+
+/// 是否可以作为 `export const` 的名字（合法的 JS 标识符且不是保留字）
+pub fn is_valid_identifier(name: &str) -> bool {
+  let mut chars = name.chars();
+
+  let Some(first) = chars.next() else {
+    return false;
+  };
+
+  (first.is_alphabetic() || first == '_' || first == '$')
+    && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+    && !RESERVED_WORDS.contains(&name)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_convert() {
+    let cases = [
+      (ExportLocalsConvention::AsIs, "foo-bar", vec!["foo-bar"]),
+      (
+        ExportLocalsConvention::CamelCase,
+        "foo-bar",
+        vec!["foo-bar", "fooBar"],
+      ),
+      (ExportLocalsConvention::CamelCase, "foo", vec!["foo"]),
+      (
+        ExportLocalsConvention::CamelCaseOnly,
+        "foo_bar-baz",
+        vec!["fooBarBaz"],
+      ),
+      (
+        ExportLocalsConvention::Dashes,
+        "foo-bar_baz",
+        vec!["foo-bar_baz", "fooBar_baz"],
+      ),
+      (ExportLocalsConvention::Dashes, "foo_bar", vec!["foo_bar"]),
+      (
+        ExportLocalsConvention::DashesOnly,
+        "foo--bar",
+        vec!["fooBar"],
+      ),
+    ];
+
+    for (convention, name, expected) in cases {
+      assert_eq!(
+        convention.convert(name),
+        expected,
+        "{:?} {}",
+        convention,
+        name
+      );
+    }
+  }
+
+  #[test]
+  fn test_camel_case() {
+    let cases = [
+      ("foo", "foo"),
+      ("foo-bar", "fooBar"),
+      ("foo_bar", "fooBar"),
+      ("foo--bar", "fooBar"),
+      ("-foo-bar-", "fooBar"),
+      ("fooBar", "fooBar"),
+      ("FOOBar", "fooBar"),
+      ("Foo", "foo"),
+      ("btn-primary-1", "btnPrimary1"),
+      ("a1b", "a1B"),
+      ("", ""),
+    ];
+
+    for (input, expected) in cases {
+      assert_eq!(camel_case(input), expected, "{}", input);
+    }
+  }
+
+  #[test]
+  fn test_dashes_camel_case() {
+    let cases = [
+      ("foo-bar", "fooBar"),
+      ("foo-bar_baz", "fooBar_baz"),
+      ("foo--bar", "fooBar"),
+      ("foo-", "foo-"),
+      ("fooBar", "fooBar"),
+    ];
+
+    for (input, expected) in cases {
+      assert_eq!(dashes_camel_case(input), expected, "{}", input);
+    }
+  }
+
+  #[test]
+  fn test_preserve_camel_case() {
+    let cases = [
+      ("foo", "foo"),
+      ("fooBar", "foo-Bar"),
+      ("fooBarBaz", "foo-Bar-Baz"),
+      ("FOOBar", "FOO-Bar"),
+      ("FOO", "FOO"),
+    ];
+
+    for (input, expected) in cases {
+      assert_eq!(preserve_camel_case(input), expected, "{}", input);
+    }
+  }
+
+  #[test]
+  fn test_is_valid_identifier() {
+    let cases = [
+      ("foo", true),
+      ("fooBar", true),
+      ("_foo", true),
+      ("$foo1", true),
+      ("foo-bar", false),
+      ("1foo", false),
+      ("foo bar", false),
+      ("", false),
+      ("default", false),
+      ("class", false),
+      ("await", false),
+    ];
+
+    for (input, expected) in cases {
+      assert_eq!(is_valid_identifier(input), expected, "{}", input);
+    }
+  }
+}
@@ -0,0 +1,307 @@
+use std::{
+  collections::{BTreeMap, HashMap},
+  path::{Component, Path, PathBuf},
+};
+
+use oxc_linter::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::{LintEntry, diagnostic::message_rule_id};
+
+/// 默认的 baseline 文件名
+pub const DEFAULT_BASELINE_FILE_NAME: &str = ".oxlint-baseline.json";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BaselineFile {
+  /// 文件（相对 baseline 文件所在目录）-> 规则 -> 已有的违规数量
+  files: BTreeMap<String, BTreeMap<String, usize>>,
+}
+
+/// lint baseline：记录已有的违规（文件 + 规则 -> 数量），只报告新增的违规
+///
+/// - baseline 文件按目录生效：从被 lint 的文件所在目录向上查找（不超过 context）最近的 baseline 文件
+/// - 某个文件某条规则的违规数量不超过 baseline 时全部忽略；超过时全部报告（无法区分哪些是新增的）
+/// - 每次编译重新读取，修改 baseline 文件后下次编译即可生效
+#[derive(Debug)]
+pub struct Baseline {
+  context: PathBuf,
+  file_name: String,
+  /// 目录 -> 该目录下的 baseline 文件（不存在时为 None）
+  loaded: HashMap<PathBuf, Option<BaselineFile>>,
+}
+
+impl Baseline {
+  pub fn new(context: impl AsRef<Path>, file_name: impl Into<String>) -> Self {
+    Self {
+      context: context.as_ref().to_path_buf(),
+      file_name: file_name.into(),
+      loaded: HashMap::new(),
+    }
+  }
+
+  /// 过滤掉 baseline 中已有的违规，返回需要报告的消息
+  pub fn filter<'a>(&mut self, file: &str, messages: &'a [Message]) -> Vec<&'a Message> {
+    let path = Path::new(file);
+
+    let Some(dir) = self.find_dir(path) else {
+      return messages.iter().collect();
+    };
+
+    let key = relative_key(&dir, path);
+
+    let Some(rules) = self
+      .loaded
+      .get(&dir)
+      .and_then(|baseline| baseline.as_ref())
+      .and_then(|baseline| baseline.files.get(&key))
+    else {
+      return messages.iter().collect();
+    };
+
+    let counts = count_by_rule(messages);
+
+    messages
+      .iter()
+      .filter(|message| match message_rule_id(message) {
+        Some(rule) => {
+          counts.get(&rule).copied().unwrap_or_default()
+            > rules.get(&rule).copied().unwrap_or_default()
+        }
+        // 没有规则名的消息不纳入 baseline
+        None => true,
+      })
+      .collect()
+  }
+
+  /// 用本轮的 lint 结果更新 baseline
+  ///
+  /// - `linted` 为本轮 lint 过的文件，它们的记录替换为当前的违规（已修复的违规会被移除）
+  /// - 本轮没有 lint 的文件保留原有记录，已删除的文件的记录会被移除
+  /// - 每个文件写入覆盖它的最近的 baseline 文件，没有时写入 context 下的 baseline 文件
+  pub fn update(
+    &mut self,
+    linted: &[String],
+    entries: &[(String, LintEntry)],
+  ) -> Result<(), String> {
+    let entries = entries
+      .iter()
+      .map(|(file, entry)| (file.as_str(), entry))
+      .collect::<HashMap<_, _>>();
+
+    let mut updated: BTreeMap<PathBuf, BaselineFile> = BTreeMap::new();
+
+    for file in linted {
+      let path = Path::new(file);
+
+      let dir = self.find_dir(path).unwrap_or_else(|| self.context.clone());
+
+      let baseline = updated.entry(dir.clone()).or_insert_with(|| {
+        let mut baseline = self
+          .loaded
+          .get(&dir)
+          .and_then(|baseline| baseline.clone())
+          .unwrap_or_default();
+
+        baseline.files.retain(|key, _| dir.join(key).exists());
+        baseline
+      });
+
+      let key = relative_key(&dir, path);
+
+      let counts = entries
+        .get(file.as_str())
+        .map(|entry| count_by_rule(&entry.messages))
+        .unwrap_or_default();
+
+      if counts.is_empty() {
+        baseline.files.remove(&key);
+      } else {
+        baseline.files.insert(key, counts);
+      }
+    }
+
+    for (dir, baseline) in updated {
+      let path = dir.join(&self.file_name);
+
+      let content = serde_json::to_string_pretty(&baseline)
+        .map_err(|e| format!("Failed to serialize lint baseline: {}", e))?;
+
+      std::fs::write(&path, content)
+        .map_err(|e| format!("Failed to write lint baseline to {:?}: {}", path, e))?;
+
+      self.loaded.insert(dir, Some(baseline));
+    }
+
+    Ok(())
+  }
+
+  /// 找到覆盖该文件的 baseline 所在目录
+  fn find_dir(&mut self, file: &Path) -> Option<PathBuf> {
+    let mut dir = file.parent();
+
+    while let Some(current) = dir {
+      if !current.starts_with(&self.context) {
+        break;
+      }
+
+      let baseline = self
+        .loaded
+        .entry(current.to_path_buf())
+        .or_insert_with(|| read_baseline(&current.join(&self.file_name)));
+
+      if baseline.is_some() {
+        return Some(current.to_path_buf());
+      }
+
+      dir = current.parent();
+    }
+
+    None
+  }
+}
+
+fn read_baseline(path: &Path) -> Option<BaselineFile> {
+  let content = std::fs::read_to_string(path).ok()?;
+  serde_json::from_str(&content).ok()
+}
+
+/// baseline 中的文件 key：相对 baseline 所在目录，统一使用 `/` 分隔
+fn relative_key(dir: &Path, file: &Path) -> String {
+  file
+    .strip_prefix(dir)
+    .unwrap_or(file)
+    .components()
+    .filter_map(|c| match c {
+      Component::Normal(name) => Some(name.to_string_lossy()),
+      _ => None,
+    })
+    .collect::<Vec<_>>()
+    .join("/")
+}
+
+fn count_by_rule(messages: &[Message]) -> BTreeMap<String, usize> {
+  let mut counts = BTreeMap::new();
+
+  for rule in messages.iter().filter_map(message_rule_id) {
+    *counts.entry(rule).or_default() += 1;
+  }
+
+  counts
+}
+
+#[cfg(test)]
+mod tests {
+  use oxc::diagnostics::OxcDiagnostic;
+  use oxc_linter::PossibleFixes;
+
+  use super::*;
+  use crate::SourceExcerpt;
+
+  fn message(rule: &str) -> Message {
+    Message::new(
+      OxcDiagnostic::warn(rule.to_string()).with_error_code("eslint", rule.to_string()),
+      PossibleFixes::None,
+    )
+  }
+
+  fn entry(rules: &[&str]) -> LintEntry {
+    LintEntry {
+      source: SourceExcerpt::default(),
+      messages: rules.iter().map(|rule| message(rule)).collect(),
+    }
+  }
+
+  #[test]
+  fn test_update_baseline() {
+    let dir = std::env::temp_dir().join("test_oxlint_update_baseline");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    for file in ["a.js", "b.js", "c.js", "d.js"] {
+      std::fs::write(dir.join(file), "").unwrap();
+    }
+
+    std::fs::write(
+      dir.join(DEFAULT_BASELINE_FILE_NAME),
+      r#"{
+        "files": {
+          "a.js": { "eslint/no-debugger": 2 },
+          "b.js": { "eslint/no-console": 1 },
+          "c.js": { "eslint/no-console": 1 },
+          "deleted.js": { "eslint/no-console": 1 }
+        }
+      }"#,
+    )
+    .unwrap();
+
+    let file = |name: &str| dir.join(name).to_string_lossy().into_owned();
+
+    let mut baseline = Baseline::new(&dir, DEFAULT_BASELINE_FILE_NAME);
+
+    // a.js 修复了一个违规，c.js 全部修复，b.js 本轮没有 lint，d.js 是新增的违规
+    baseline
+      .update(
+        &[file("a.js"), file("c.js"), file("d.js")],
+        &[
+          (file("a.js"), entry(&["no-debugger"])),
+          (file("d.js"), entry(&["no-console", "no-console"])),
+        ],
+      )
+      .unwrap();
+
+    let updated = read_baseline(&dir.join(DEFAULT_BASELINE_FILE_NAME)).unwrap();
+
+    assert_eq!(
+      updated.files,
+      BTreeMap::from([
+        (
+          "a.js".to_string(),
+          BTreeMap::from([("eslint/no-debugger".to_string(), 1)])
+        ),
+        (
+          "b.js".to_string(),
+          BTreeMap::from([("eslint/no-console".to_string(), 1)])
+        ),
+        (
+          "d.js".to_string(),
+          BTreeMap::from([("eslint/no-console".to_string(), 2)])
+        ),
+      ])
+    );
+  }
+
+  #[test]
+  fn test_filter_baseline() {
+    let dir = std::env::temp_dir().join("test_oxlint_filter_baseline");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    std::fs::write(
+      dir.join(DEFAULT_BASELINE_FILE_NAME),
+      r#"{ "files": { "a.js": { "eslint/no-debugger": 1, "eslint/no-console": 1 } } }"#,
+    )
+    .unwrap();
+
+    let mut baseline = Baseline::new(&dir, DEFAULT_BASELINE_FILE_NAME);
+
+    let messages = vec![
+      message("no-debugger"),
+      message("no-console"),
+      message("no-console"),
+    ];
+
+    // 未超过 baseline 的违规全部忽略，超过时全部报告
+    let reported = baseline.filter(&dir.join("a.js").to_string_lossy(), &messages);
+    assert_eq!(reported.len(), 2);
+    assert!(
+      reported
+        .iter()
+        .all(|message| message_rule_id(message).as_deref() == Some("eslint/no-console"))
+    );
+
+    // 不在 baseline 中的文件全部报告
+    let reported = baseline.filter(&dir.join("b.js").to_string_lossy(), &messages);
+    assert_eq!(reported.len(), 3);
+  }
+}
@@ -0,0 +1,151 @@
+use std::{
+  path::{Path, PathBuf},
+  process::Command,
+};
+
+use rustc_hash::FxHashSet;
+
+/// 默认对比的 git 分支
+const DEFAULT_GIT_BASE: &str = "main";
+
+/// 只 lint 变更文件的配置
+///
+/// - `files`: 由调用方直接提供的文件列表（相对路径基于 compilation 的 context），优先级高于 `base`
+/// - `base`: git 对比基准（分支、tag 或 commit），与 `HEAD` 求 merge-base 后取差异文件，默认为 `main`
+#[derive(Debug, Clone, Default)]
+pub struct ChangedFilesOpts {
+  pub base: Option<String>,
+  pub files: Option<Vec<String>>,
+}
+
+impl ChangedFilesOpts {
+  /// 解析出需要 lint 的文件集合（规范化后的绝对路径）
+  ///
+  /// 只读取本地 git 仓库状态（merge-base / diff / 未跟踪文件），不会访问远程
+  pub fn resolve(&self, context: &Path) -> Result<FxHashSet<PathBuf>, String> {
+    let files = match &self.files {
+      Some(files) => files.iter().map(|f| context.join(f)).collect::<Vec<_>>(),
+      None => {
+        let base = self.base.as_deref().unwrap_or(DEFAULT_GIT_BASE);
+        git_changed_files(context, base)?
+      }
+    };
+
+    Ok(
+      files
+        .into_iter()
+        .filter_map(|f| f.canonicalize().ok())
+        .collect(),
+    )
+  }
+}
+
+fn git(cwd: &Path, args: &[&str]) -> Result<String, String> {
+  // 关闭 quotePath，避免非 ASCII 文件名被转义成八进制
+  let output = Command::new("git")
+    .args(["-c", "core.quotePath=false"])
+    .args(args)
+    .current_dir(cwd)
+    .output()
+    .map_err(|e| format!("Failed to run git {}: {}", args.join(" "), e))?;
+
+  if !output.status.success() {
+    return Err(format!(
+      "git {} failed: {}",
+      args.join(" "),
+      String::from_utf8_lossy(&output.stderr).trim()
+    ));
+  }
+
+  Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// 获取相对 `base` 的变更文件（已提交的差异 + 工作区修改 + 未跟踪文件）
+///
+/// 使用 `-z` 输出，文件名以 `\0` 分隔且不做转义，支持包含空格、换行等特殊字符的路径
+fn git_changed_files(context: &Path, base: &str) -> Result<Vec<PathBuf>, String> {
+  let root = PathBuf::from(git(context, &["rev-parse", "--show-toplevel"])?.trim());
+
+  let merge_base = git(context, &["merge-base", "HEAD", base])?;
+  let merge_base = merge_base.trim();
+
+  let changed = git(
+    &root,
+    &[
+      "diff",
+      "--name-only",
+      "-z",
+      "--diff-filter=ACMR",
+      merge_base,
+    ],
+  )?;
+
+  let untracked = git(&root, &["ls-files", "-z", "--others", "--exclude-standard"])?;
+
+  Ok(
+    changed
+      .split('\0')
+      .chain(untracked.split('\0'))
+      .filter(|file| !file.is_empty())
+      .map(|file| root.join(file))
+      .collect(),
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn run_git(cwd: &Path, args: &[&str]) {
+    let status = Command::new("git")
+      .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+      .args(args)
+      .current_dir(cwd)
+      .status()
+      .unwrap();
+    assert!(status.success(), "git {} failed", args.join(" "));
+  }
+
+  #[test]
+  fn test_git_changed_files() {
+    let dir = std::env::temp_dir().join("test_oxlint_changed_files");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    run_git(&dir, &["init", "-q", "-b", "main"]);
+    for file in ["unchanged.js", "modified.js"] {
+      std::fs::write(dir.join(file), "").unwrap();
+    }
+    run_git(&dir, &["add", "."]);
+    run_git(&dir, &["commit", "-q", "-m", "init"]);
+
+    run_git(&dir, &["checkout", "-q", "-b", "feature"]);
+    std::fs::write(dir.join("committed.js"), "").unwrap();
+    std::fs::write(dir.join("中文 文件.js"), "").unwrap();
+    run_git(&dir, &["add", "."]);
+    run_git(&dir, &["commit", "-q", "-m", "feature"]);
+
+    // 工作区修改与未跟踪文件
+    std::fs::write(dir.join("modified.js"), "debugger;").unwrap();
+    std::fs::write(dir.join("untracked.js"), "").unwrap();
+    std::fs::write(dir.join("未跟踪.js"), "").unwrap();
+
+    let files = ChangedFilesOpts::default().resolve(&dir).unwrap();
+
+    let root = dir.canonicalize().unwrap();
+    let expected = [
+      "committed.js",
+      "中文 文件.js",
+      "modified.js",
+      "untracked.js",
+      "未跟踪.js",
+    ]
+    .iter()
+    .map(|file| root.join(file))
+    .collect::<FxHashSet<_>>();
+
+    assert_eq!(files, expected);
+
+    let _ = std::fs::remove_dir_all(&dir);
+  }
+}
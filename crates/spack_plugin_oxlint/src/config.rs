@@ -1,9 +1,55 @@
-use std::{collections::HashMap, path::Path};
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
 
+use derive_more::Debug;
+use futures::future::BoxFuture;
 use oxc_linter::Oxlintrc;
 use serde_json::{Value, from_value, json, to_value};
 
-use crate::{Environment, Restricted};
+use crate::{
+  ChangedFilesOpts, Environment, FixMode, Framework, LintPlugin, LintReport, OxlintPluginResp,
+  Preset, Restricted, RestrictedImport, RestrictedImportPattern, RestrictedImportScope,
+  UnusedDirectives,
+  config_merge::{
+    find_nested_config_files, load_config_chain, merge_config, merge_rules, rule_alias,
+  },
+  default_plugins, detect_frameworks,
+  preset::{JSX_FILES, TYPESCRIPT_FILES},
+  restricted::restricted_imports_rule,
+};
+
+/// 生成的内置配置文件名（位于 `output_dir` 下）
+pub const GENERATED_CONFIG_FILE_NAME: &str = ".oxlintrc.json";
+
+/// 嵌套配置文件名，开启 `nested_configs` 时对所在目录及其子目录生效
+pub const NESTED_CONFIG_FILE_NAME: &str = ".oxlintrc.json";
+
+/// 最终生效配置的文件名（位于 `output_dir` 下），只用于排查问题
+pub const EFFECTIVE_CONFIG_FILE_NAME: &str = ".oxlintrc.effective.json";
+
+/// lint 完成后的回调，插件选项需要 Clone（统一插件会复制一份），所以使用 Arc
+pub type LintedHookFn = Arc<
+  dyn Fn(OxlintPluginResp) -> BoxFuture<'static, Result<(), Box<dyn std::error::Error>>>
+    + Sync
+    + Send,
+>;
+
+/// JS 传入的 lint 完成回调（与其它插件的 `CompilationHookFn` 一致），存入插件选项时转换为 `LintedHookFn`
+pub type CompilationHookFn = Box<
+  dyn Fn(OxlintPluginResp) -> BoxFuture<'static, Result<(), Box<dyn std::error::Error>>>
+    + Sync
+    + Send,
+>;
+
+/// 插件的回调选项，由 binding 通过 `ThreadsafeCallback` 派生宏从 JS 回调转换而来
+#[derive(Debug)]
+pub struct OxlintHookOpts {
+  #[debug(skip)]
+  pub on_linted: Option<CompilationHookFn>,
+}
 
 /// Oxlint 插件配置选项
 #[derive(Debug, Clone)]
@@ -11,67 +57,241 @@ pub struct OxlintPluginOpts {
   pub output_dir: String,
   pub show_warning: bool,
   pub fail_on_error: bool,
-  pub restricted_imports: Vec<Restricted>,
+  pub restricted_imports: Vec<RestrictedImport>,
+  /// 按模式（glob / 正则）限制的导入
+  pub restricted_import_patterns: Vec<RestrictedImportPattern>,
+  /// 只对部分文件生效的导入限制
+  pub restricted_import_scopes: Vec<RestrictedImportScope>,
   pub restricted_globals: Vec<Restricted>,
   pub globals: HashMap<String, bool>,
   pub environments: Environment,
   pub config_file_path: Option<String>,
+  /// 自动修复模式，开启后会把修复结果直接写回源文件（不会修改 node_modules 下的文件）
+  ///
+  /// 只在首次编译时修复，watch 模式下重新编译时只报告
+  pub fix: FixMode,
+  /// 需要输出的 lint 报告（json / sarif / junit / checkstyle）
+  pub reports: Vec<LintReport>,
+  /// 是否把 lint 结果持久化到 `output_dir`，下次启动时复用未修改文件的结果
+  ///
+  /// 缓存只以文件自身的内容为 key，开启 import 插件时不使用（跨模块规则的结果依赖其它文件）
+  pub cache: bool,
+  /// 首次全量 lint 时只检查变更的文件（用于 PR 的 CI），为 None 时检查所有文件
+  pub changed_files: Option<ChangedFilesOpts>,
+  /// 需要 lint 的文件 glob（相对 compilation 的 context），为空时使用内置的 js/ts 扩展名列表
+  pub include: Vec<String>,
+  /// 额外排除的文件 glob（相对 compilation 的 context），在内置排除规则（node_modules、dist 等）之上追加
+  pub exclude: Vec<String>,
+  /// 只 lint 出现在模块图中的模块（不遍历 context 下的所有文件），适合位于大型 monorepo 中的应用
+  pub reachable_only: bool,
+  /// 已 lint 但不在模块图中的文件报告的输出路径（相对 compilation 的 context），只在非 `reachable_only` 模式下生效
+  pub unreachable_report: Option<String>,
+  /// 叠加在内置规则集上的预设，按顺序合并
+  pub presets: Vec<Preset>,
+  /// 规则分类的严重级别覆盖（如 `{ "correctness": "error" }`），合并到内置配置上
+  pub categories: HashMap<String, Value>,
+  /// 单条规则的覆盖（如 `{ "no-debugger": "error" }`），最后合并，优先级最高
+  ///
+  /// 只给出严重级别时，保留内置配置中该规则的选项
+  pub rules: HashMap<String, Value>,
+  /// 需要 lint 内联 `<script>` 的 HTML/EJS 模板 glob（相对 compilation 的 context）
+  pub templates: Vec<String>,
+  /// 允许的最大警告数（不包括 baseline 中已有的警告），超过时构建失败
+  pub max_warnings: Option<usize>,
+  /// baseline 文件名，配置后只报告 baseline 之外新增的违规
+  ///
+  /// baseline 文件按目录生效，从被 lint 的文件所在目录向上查找最近的 baseline 文件
+  pub baseline: Option<String>,
+  /// 是否用首次编译的 lint 结果更新 baseline（用于首次生成或开启更严格的规则后更新）
+  ///
+  /// 只更新 lint 过的文件的记录，其它文件的记录保留（已删除的文件除外）
+  pub update_baseline: bool,
+  /// 未使用的 disable 指令的报告级别
+  pub unused_directives: UnusedDirectives,
+  /// 项目使用的框架，为 None 时根据项目根目录（rspack 的 `context`）下 package.json 的依赖自动检测
+  pub frameworks: Option<Vec<Framework>>,
+  /// 开启的 oxlint 插件，为空时使用通用插件加上框架对应的插件
+  pub plugins: Vec<LintPlugin>,
+  /// 是否查找嵌套配置（项目根目录下各子目录中的 `.oxlintrc.json`），用于 monorepo 中各个包使用不同的规则
+  ///
+  /// 每个文件使用离它最近的嵌套配置，嵌套配置合并在插件的有效配置之上（插件选项中的覆盖仍然优先）
+  pub nested_configs: bool,
+  /// 是否收集 lint 统计（规则命中次数、文件耗时、累计耗时），每轮编译后输出汇总表格并附加到 `on_linted` 的参数中
+  ///
+  /// 不包括单条规则的耗时：oxlint 在一次遍历中运行所有规则，没有暴露规则维度的耗时
+  pub stats: bool,
+  /// 是否把 lint 消息转换为适合 dev server overlay 展示的诊断（纯文本 code frame + 规则文档链接），热更新时未变化的警告不重复展示 code frame
+  pub overlay: bool,
+  /// 每轮编译 lint 完成后的回调，参数为每个文件的 lint 消息和总数
+  #[debug(skip)]
+  pub on_linted: Option<LintedHookFn>,
+}
+
+/// 解析后的 lint 配置
+#[derive(Debug)]
+pub struct ResolvedConfig {
+  pub oxlintrc: Oxlintrc,
+  /// 按目录生效的嵌套配置（未开启 `nested_configs` 时为空）
+  pub nested: Vec<NestedConfig>,
+  /// 用户配置文件、嵌套配置文件及其 `extends` 链上的所有文件
+  pub files: Vec<PathBuf>,
+}
+
+/// 嵌套配置，对 `dir` 及其子目录中的文件生效（更深的嵌套配置优先）
+#[derive(Debug)]
+pub struct NestedConfig {
+  pub dir: PathBuf,
+  pub oxlintrc: Oxlintrc,
 }
 
 impl OxlintPluginOpts {
   /// 构建 Oxlintrc 配置
+  pub fn build_oxlintrc(&self, context: &Path) -> Result<Oxlintrc, String> {
+    self.resolve_config(context).map(|config| config.oxlintrc)
+  }
+
+  /// 构建 Oxlintrc 配置，同时返回用户配置文件及其 `extends` 链上的所有文件
+  ///
+  /// 总是先生成内置配置文件到 `output_dir/.oxlintrc.json`（兼容在用户配置中 extends 该文件的用法）
+  /// 如果提供了 `config_file_path`，则加载用户配置及其 `extends` 链，依次合并到内置配置上，
+  /// 最后再合并插件选项中的 `categories`/`rules` 覆盖
+  ///
+  /// 开启 `nested_configs` 时，再以该配置为基础加载各目录中的嵌套配置
+  ///
+  /// 最终生效的配置会写入 `output_dir/.oxlintrc.effective.json`，便于排查问题
   ///
-  /// 总是先生成内置配置文件到 `output_dir/.oxlintrc.json`（用于用户 extends）
-  /// 如果提供了 `config_file_path`，则从用户文件加载；
-  /// 否则使用生成的内置配置
-  pub fn build_oxlintrc(&self) -> Result<Oxlintrc, String> {
+  /// `context` 为项目根目录（rspack 的 `context`），用于检测框架、查找嵌套配置以及作为限制导入的文件 glob 的根目录
+  pub fn resolve_config(&self, context: &Path) -> Result<ResolvedConfig, String> {
     // 1. 总是生成内置配置文件（即使用户提供了自定义配置）
-    // 这样用户可以在自己的配置文件中 extends 这个生成的文件
-    let config_json = self
-      .build_config_json()
+    let mut config_json = self
+      .build_config_json(context)
       .map_err(|e| format!("Failed to build config JSON: {}", e))?;
 
-    let config_output_path = Path::new(&self.output_dir).join(".oxlintrc.json");
+    let config_output_path = Path::new(&self.output_dir).join(GENERATED_CONFIG_FILE_NAME);
     write_config_file(&config_json, &config_output_path, &self.output_dir)?;
 
-    // 2. 如果用户提供了自定义配置文件，加载用户的配置
-    // 否则使用生成的内置配置
+    let mut files = vec![];
+
+    // 2. 如果用户提供了自定义配置文件，把用户配置（及其 extends 链）合并到内置配置上
     if let Some(file_path) = &self.config_file_path {
-      Self::load_from_file(file_path)
+      let chain = load_config_chain(Path::new(file_path), &config_output_path)?;
+
+      for layer in chain.layers {
+        merge_config(&mut config_json, layer);
+      }
+
+      files = chain.files;
+
+      // 插件选项中的覆盖优先级最高
+      self.apply_rule_overrides(&mut config_json);
+    }
+
+    // 3. 输出最终生效的配置
+    let effective_output_path = Path::new(&self.output_dir).join(EFFECTIVE_CONFIG_FILE_NAME);
+    write_config_file(&config_json, &effective_output_path, &self.output_dir)?;
+
+    // 4. 加载嵌套配置
+    let nested = if self.nested_configs {
+      self.resolve_nested_configs(context, &config_json, &config_output_path, &mut files)?
     } else {
-      // 使用生成的配置
-      from_value::<Oxlintrc>(config_json)
-        .map_err(|e| format!("Failed to deserialize Oxlintrc: {}", e))
+      vec![]
+    };
+
+    let oxlintrc = from_value::<Oxlintrc>(config_json)
+      .map_err(|e| format!("Failed to deserialize Oxlintrc: {}", e))?;
+
+    Ok(ResolvedConfig {
+      oxlintrc,
+      nested,
+      files,
+    })
+  }
+
+  /// 查找 `context` 下的所有嵌套配置，依次把嵌套配置（及其 extends 链）合并到 `base` 上
+  ///
+  /// `config_file_path` 指向的用户配置本身不会再作为嵌套配置加载
+  fn resolve_nested_configs(
+    &self,
+    context: &Path,
+    base: &Value,
+    generated: &Path,
+    files: &mut Vec<PathBuf>,
+  ) -> Result<Vec<NestedConfig>, String> {
+    let user_config = self
+      .config_file_path
+      .as_ref()
+      .and_then(|file_path| Path::new(file_path).canonicalize().ok());
+
+    let mut nested = vec![];
+
+    for file_path in find_nested_config_files(context, Path::new(&self.output_dir)) {
+      if user_config.as_ref() == Some(&file_path) {
+        continue;
+      }
+
+      let Some(dir) = file_path.parent() else {
+        continue;
+      };
+
+      let chain = load_config_chain(&file_path, generated)?;
+
+      let mut config_json = base.clone();
+
+      for layer in chain.layers {
+        merge_config(&mut config_json, layer);
+      }
+
+      self.apply_rule_overrides(&mut config_json);
+
+      files.extend(chain.files);
+
+      let oxlintrc = from_value::<Oxlintrc>(config_json).map_err(|e| {
+        format!(
+          "Failed to deserialize nested oxlintrc {:?}: {}",
+          file_path, e
+        )
+      })?;
+
+      nested.push(NestedConfig {
+        dir: dir.to_path_buf(),
+        oxlintrc,
+      });
+    }
+
+    Ok(nested)
+  }
+
+  /// 项目使用的框架，未配置时根据 `context` 下的 package.json 检测
+  pub fn resolve_frameworks(&self, context: &Path) -> Vec<Framework> {
+    match &self.frameworks {
+      Some(frameworks) => frameworks.clone(),
+      None => detect_frameworks(context),
     }
   }
 
-  /// 从外部配置文件加载
-  fn load_from_file(file_path: &str) -> Result<Oxlintrc, String> {
-    Oxlintrc::from_file(Path::new(file_path)).map_err(|e| {
-      format!(
-        "Failed to load oxlintrc file: {}, error: {:?}",
-        file_path, e
-      )
-    })
+  /// 开启的 oxlint 插件，未配置时根据使用的框架确定
+  fn resolve_plugins(&self, context: &Path) -> Vec<LintPlugin> {
+    if self.plugins.is_empty() {
+      default_plugins(&self.resolve_frameworks(context))
+    } else {
+      self.plugins.clone()
+    }
   }
 
   /// 构建 Oxlint 配置的 JSON 表示
   ///
   /// 包含所有 lint 规则、环境、全局变量等配置
-  fn build_config_json(&self) -> serde_json::Result<Value> {
-    let restricted_imports = to_value(&self.restricted_imports)?;
+  fn build_config_json(&self, context: &Path) -> serde_json::Result<Value> {
+    let lint_plugins = self.resolve_plugins(context);
+    let plugins = to_value(&lint_plugins)?;
+    let restricted_imports =
+      restricted_imports_rule(&self.restricted_imports, &self.restricted_import_patterns);
     let restricted_globals = to_value(&self.restricted_globals)?;
     let globals = to_value(&self.globals)?;
     let environments = to_value(&self.environments)?;
 
-    let config = json!({
-      "plugins": [
-        "eslint",
-        "typescript",
-        "unicorn",
-        "react",
-        "oxc"
-      ],
+    let mut config = json!({
+      "plugins": plugins,
       "categories": {
         "correctness": "off",
         "suspicious": "off",
@@ -157,10 +377,7 @@ impl OxlintPluginOpts {
         "eslint/no-regex-spaces":[2],
         // TODO: 添加 no-restricted-globals 规则
         "no-restricted-globals": [1, restricted_globals],
-        // TODO: 添加 restricted-imports 规则
-        "no-restricted-imports": [1, {
-          "paths": restricted_imports
-        }],
+        "no-restricted-imports": restricted_imports,
         "eslint/no-undefined":[0],
         "eslint/no-var":[2],
         "eslint/no-void":[0],
@@ -317,13 +534,13 @@ impl OxlintPluginOpts {
       "env":environments,
       "globals": globals,
       "overrides":[{
-        "files": ["*.{ts,tsx,cts,mts}"],
+        "files": [TYPESCRIPT_FILES],
         "env": {},
         "globals": {},
         "plugins": [],
         "rules":{}
       },{
-        "files": ["*.{jsx,tsx}"],
+        "files": [JSX_FILES],
         "env": {},
         "globals": {},
         "plugins": [],
@@ -332,8 +549,86 @@ impl OxlintPluginOpts {
       "ignorePatterns":[]
     });
 
+    // import 插件的跨模块规则（依赖共享的 module record），`plugins` 中关闭 import 插件时不添加
+    if lint_plugins.contains(&LintPlugin::Import)
+      && let Some(rules) = config["rules"].as_object_mut()
+    {
+      rules.extend([
+        ("import/default".to_string(), json!([2])),
+        ("import/named".to_string(), json!([2])),
+        ("import/namespace".to_string(), json!([2])),
+        ("import/no-cycle".to_string(), json!([1])),
+        ("import/no-self-import".to_string(), json!([2])),
+      ]);
+    }
+
+    // 只对部分文件生效的导入限制，追加在内置 override 之后
+    if !self.restricted_import_scopes.is_empty()
+      && let Some(overrides) = config["overrides"].as_array_mut()
+    {
+      for scope in &self.restricted_import_scopes {
+        overrides.push(scope.to_override(
+          context,
+          &self.restricted_imports,
+          &self.restricted_import_patterns,
+        ));
+      }
+    }
+
+    self.apply_overrides(&mut config);
+
     Ok(config)
   }
+
+  /// 依次合并预设、分类覆盖和规则覆盖
+  fn apply_overrides(&self, config: &mut Value) {
+    for preset in &self.presets {
+      preset.apply(config);
+    }
+
+    self.apply_rule_overrides(config);
+  }
+
+  /// 合并插件选项中的分类覆盖和规则覆盖（优先级高于内置配置和用户配置文件）
+  ///
+  /// override 中配置了同一条规则时，override 的配置同样会被覆盖，否则对匹配的文件不生效
+  fn apply_rule_overrides(&self, config: &mut Value) {
+    if let Some(categories) = config["categories"].as_object_mut() {
+      for (category, severity) in &self.categories {
+        categories.insert(category.clone(), severity.clone());
+      }
+    }
+
+    if let Some(rules) = config["rules"].as_object_mut() {
+      merge_rules(
+        rules,
+        self
+          .rules
+          .iter()
+          .map(|(name, value)| (name.clone(), value.clone()))
+          .collect(),
+      );
+    }
+
+    if let Some(overrides) = config["overrides"].as_array_mut() {
+      for rules in overrides
+        .iter_mut()
+        .filter_map(|item| item.get_mut("rules").and_then(Value::as_object_mut))
+      {
+        let matched = self
+          .rules
+          .iter()
+          .filter(|(name, _)| {
+            rules.contains_key(name.as_str())
+              || rule_alias(name).is_some_and(|alias| rules.contains_key(&alias))
+          })
+          .map(|(name, value)| (name.clone(), value.clone()))
+          .collect();
+
+        merge_rules(rules, matched);
+      }
+    }
+  }
 }
 
 /// 将配置写入文件
@@ -363,25 +658,205 @@ fn write_config_file(
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_build_config_json() {
-    let opts = OxlintPluginOpts {
+  /// 测试用的默认选项，各测试只覆盖需要验证的字段
+  fn test_opts() -> OxlintPluginOpts {
+    OxlintPluginOpts {
       output_dir: "/tmp".to_string(),
       show_warning: true,
       fail_on_error: true,
       restricted_imports: vec![],
+      restricted_import_patterns: vec![],
+      restricted_import_scopes: vec![],
       restricted_globals: vec![],
       globals: HashMap::new(),
       environments: Environment::default(),
       config_file_path: None,
-    };
+      fix: FixMode::None,
+      reports: vec![],
+      cache: false,
+      changed_files: None,
+      include: vec![],
+      exclude: vec![],
+      reachable_only: false,
+      unreachable_report: None,
+      presets: vec![],
+      categories: HashMap::new(),
+      rules: HashMap::new(),
+      templates: vec![],
+      max_warnings: None,
+      baseline: None,
+      update_baseline: false,
+      unused_directives: UnusedDirectives::Error,
+      frameworks: Some(vec![]),
+      plugins: vec![],
+      nested_configs: false,
+      stats: false,
+      overlay: false,
+      on_linted: None,
+    }
+  }
+
+  #[test]
+  fn test_build_config_json() {
+    let opts = test_opts();
 
-    let result = opts.build_config_json();
+    let result = opts.build_config_json(Path::new("."));
 
     assert!(result.is_ok());
     let config = result.unwrap();
     assert!(config.get("rules").is_some());
     assert!(config.get("plugins").is_some());
+
+    // 默认开启 import 插件及其跨模块规则
+    assert!(
+      config["plugins"]
+        .as_array()
+        .unwrap()
+        .contains(&json!("import"))
+    );
+    assert!(config["rules"].get("import/no-cycle").is_some());
+  }
+
+  #[test]
+  fn test_rule_overrides() {
+    let opts = OxlintPluginOpts {
+      presets: vec![Preset::Strict],
+      categories: HashMap::from([("perf".to_string(), json!("off"))]),
+      rules: HashMap::from([
+        ("no-debugger".to_string(), json!("error")),
+        ("eslint/max-lines".to_string(), json!("off")),
+      ]),
+      ..test_opts()
+    };
+
+    let config = opts.build_config_json(Path::new(".")).unwrap();
+
+    // 预设先合并，分类覆盖优先级更高
+    assert_eq!(config["categories"]["correctness"], json!("error"));
+    assert_eq!(config["categories"]["perf"], json!("off"));
+
+    // 简写规则名会替换 `eslint/` 前缀的写法
+    assert_eq!(config["rules"]["no-debugger"], json!(["error"]));
+    assert!(config["rules"].get("eslint/no-debugger").is_none());
+
+    // 只覆盖严重级别时保留规则选项
+    assert_eq!(config["rules"]["eslint/max-lines"][0], json!("off"));
+    assert_eq!(config["rules"]["eslint/max-lines"][1]["max"], json!(1000));
+  }
+
+  #[test]
+  fn test_preset_rule_overrides() {
+    let opts = OxlintPluginOpts {
+      presets: vec![Preset::Typescript, Preset::React],
+      rules: HashMap::from([
+        ("typescript/no-explicit-any".to_string(), json!("off")),
+        ("react/exhaustive-deps".to_string(), json!("error")),
+      ]),
+      ..test_opts()
+    };
+
+    let config = opts.build_config_json(Path::new(".")).unwrap();
+
+    let override_rules = |files: &str| {
+      config["overrides"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["files"] == json!([files]))
+        .map(|item| item["rules"].clone())
+        .unwrap()
+    };
+
+    // 预设按 `files` 合并到对应的 override
+    let typescript = override_rules(TYPESCRIPT_FILES);
+    assert_eq!(typescript["typescript/prefer-as-const"], json!([2]));
+
+    // 插件选项中的规则覆盖同样作用于 override 中的规则
+    assert_eq!(typescript["typescript/no-explicit-any"], json!(["off"]));
+    assert_eq!(
+      override_rules(JSX_FILES)["react/exhaustive-deps"],
+      json!(["error"])
+    );
+  }
+
+  #[test]
+  fn test_merge_user_config() {
+    use std::env;
+
+    let temp_dir = env::temp_dir().join("test_oxlint_merge_user_config");
+    std::fs::create_dir_all(&temp_dir).unwrap();
+
+    std::fs::write(
+      temp_dir.join("base.json"),
+      r#"{ "rules": { "eqeqeq": "off", "no-debugger": "warn" } }"#,
+    )
+    .unwrap();
+    std::fs::write(
+      temp_dir.join("user.json"),
+      r#"{
+        // 支持注释
+        "extends": ["./base.json", "./.oxlintrc.json"],
+        "rules": { "no-debugger": "error" }
+      }"#,
+    )
+    .unwrap();
+
+    let opts = OxlintPluginOpts {
+      output_dir: temp_dir.to_string_lossy().to_string(),
+      config_file_path: Some(temp_dir.join("user.json").to_string_lossy().to_string()),
+      ..test_opts()
+    };
+
+    let result = opts.build_oxlintrc(Path::new("."));
+    assert!(result.is_ok());
+
+    let effective: Value = serde_json::from_str(
+      &std::fs::read_to_string(temp_dir.join(EFFECTIVE_CONFIG_FILE_NAME)).unwrap(),
+    )
+    .unwrap();
+
+    // 内置规则仍然存在，用户配置及其 extends 覆盖在其上
+    assert!(effective["rules"].get("eslint/no-var").is_some());
+    // 同一条规则的两种写法只保留一种
+    assert_eq!(
+      ["no-debugger", "eslint/no-debugger"]
+        .iter()
+        .filter(|name| effective["rules"].get(**name).is_some())
+        .count(),
+      1
+    );
+    assert!(effective.get("extends").is_none());
+
+    let _ = std::fs::remove_dir_all(&temp_dir);
+  }
+
+  #[test]
+  fn test_nested_configs_from_context() {
+    use std::env;
+
+    let temp_dir = env::temp_dir().join("test_oxlint_nested_configs");
+    let package_dir = temp_dir.join("packages").join("a");
+    std::fs::create_dir_all(&package_dir).unwrap();
+
+    std::fs::write(
+      package_dir.join(NESTED_CONFIG_FILE_NAME),
+      r#"{ "rules": { "no-debugger": "off" } }"#,
+    )
+    .unwrap();
+
+    let opts = OxlintPluginOpts {
+      output_dir: temp_dir.join("dist").to_string_lossy().to_string(),
+      nested_configs: true,
+      ..test_opts()
+    };
+
+    // 嵌套配置从 context 开始查找，与进程的工作目录无关
+    let config = opts.resolve_config(&temp_dir).unwrap();
+
+    assert_eq!(config.nested.len(), 1);
+    assert!(config.nested[0].dir.ends_with("packages/a"));
+
+    let _ = std::fs::remove_dir_all(&temp_dir);
   }
 
   #[test]
@@ -393,16 +868,10 @@ mod tests {
 
     let opts = OxlintPluginOpts {
       output_dir,
-      show_warning: true,
-      fail_on_error: true,
-      restricted_imports: vec![],
-      restricted_globals: vec![],
-      globals: HashMap::new(),
-      environments: Environment::default(),
-      config_file_path: None,
+      ..test_opts()
     };
 
-    let result = opts.build_oxlintrc();
+    let result = opts.build_oxlintrc(Path::new("."));
 
     assert!(result.is_ok());
 
@@ -0,0 +1,195 @@
+use std::{
+  collections::HashSet,
+  path::{Path, PathBuf},
+};
+
+use ignore::WalkBuilder;
+use oxc_linter::Oxlintrc;
+use serde_json::{Map, Value};
+
+use crate::NESTED_CONFIG_FILE_NAME;
+
+/// 用户配置文件及其 `extends` 链
+#[derive(Debug, Default)]
+pub(crate) struct ConfigChain {
+  /// 按合并顺序排列的配置（被 extends 的配置在前）
+  pub layers: Vec<Value>,
+  /// 链上所有配置文件（规范化后的绝对路径），用于 watch 模式下监听配置变化
+  pub files: Vec<PathBuf>,
+}
+
+/// 加载用户配置文件及其 `extends` 链
+///
+/// - `extends` 中的路径相对于声明它的配置文件
+/// - 指向 `generated`（生成的内置配置）的 extends 会被跳过，内置配置总是作为最底层
+/// - 循环 extends 会被忽略
+pub(crate) fn load_config_chain(file_path: &Path, generated: &Path) -> Result<ConfigChain, String> {
+  let mut chain = ConfigChain::default();
+  let mut visited = HashSet::new();
+
+  if let Some(generated) = canonicalize(generated) {
+    visited.insert(generated);
+  }
+
+  load_layers(file_path, &mut visited, &mut chain)?;
+
+  Ok(chain)
+}
+
+fn load_layers(
+  file_path: &Path,
+  visited: &mut HashSet<PathBuf>,
+  chain: &mut ConfigChain,
+) -> Result<(), String> {
+  let Some(canonical_path) = canonicalize(file_path) else {
+    return Err(format!("Oxlint config file not found: {:?}", file_path));
+  };
+
+  if !visited.insert(canonical_path.clone()) {
+    return Ok(());
+  }
+
+  chain.files.push(canonical_path);
+
+  // 通过 oxlint 加载，支持带注释的 JSON
+  let oxlintrc = Oxlintrc::from_file(file_path).map_err(|e| {
+    format!(
+      "Failed to load oxlintrc file: {:?}, error: {:?}",
+      file_path, e
+    )
+  })?;
+
+  let base_dir = file_path.parent().unwrap_or(Path::new(""));
+
+  for extend in &oxlintrc.extends {
+    load_layers(&base_dir.join(extend), visited, chain)?;
+  }
+
+  let mut layer = serde_json::to_value(&oxlintrc)
+    .map_err(|e| format!("Failed to serialize oxlintrc {:?}: {}", file_path, e))?;
+
+  if let Some(layer) = layer.as_object_mut() {
+    layer.remove("extends");
+    layer.remove("$schema");
+  }
+
+  chain.layers.push(layer);
+
+  Ok(())
+}
+
+/// 查找 `root` 下所有的嵌套配置文件（规范化后的绝对路径，按路径排序）
+///
+/// 遵循 `.gitignore`，跳过 node_modules、.git 以及插件的输出目录 `output_dir`（其中有生成的内置配置）
+pub(crate) fn find_nested_config_files(root: &Path, output_dir: &Path) -> Vec<PathBuf> {
+  let output_dir = canonicalize(output_dir);
+
+  let mut files = WalkBuilder::new(root)
+    // 配置文件以 `.` 开头，不能跳过隐藏文件
+    .hidden(false)
+    .filter_entry(move |entry| {
+      let is_dir = entry.file_type().is_some_and(|ft| ft.is_dir());
+
+      if !is_dir {
+        return entry.file_name() == NESTED_CONFIG_FILE_NAME;
+      }
+
+      if entry.file_name() == "node_modules" || entry.file_name() == ".git" {
+        return false;
+      }
+
+      output_dir.is_none() || canonicalize(entry.path()) != output_dir
+    })
+    .build()
+    .filter_map(|entry| entry.ok())
+    .filter(|entry| entry.file_type().is_some_and(|ft| ft.is_file()))
+    .filter_map(|entry| canonicalize(entry.path()))
+    .collect::<Vec<_>>();
+
+  files.sort();
+  files
+}
+
+fn canonicalize(path: &Path) -> Option<PathBuf> {
+  path.canonicalize().ok()
+}
+
+/// 把一层配置合并到 `base` 上
+///
+/// - `plugins`: 取并集
+/// - `rules`: 按规则合并，只给出严重级别时保留原有的规则选项
+/// - `overrides` / `ignorePatterns`: 追加
+/// - `categories` / `env` / `globals` / `settings`: 深度合并
+/// - 其它字段: 直接覆盖
+pub(crate) fn merge_config(base: &mut Value, layer: Value) {
+  let Value::Object(layer) = layer else {
+    return;
+  };
+
+  for (key, value) in layer {
+    // 未配置的字段（序列化为 null）不覆盖内置配置
+    if value.is_null() {
+      continue;
+    }
+
+    match (key.as_str(), &mut base[&key], value) {
+      ("plugins", Value::Array(base), Value::Array(plugins)) => {
+        for plugin in plugins {
+          if !base.contains(&plugin) {
+            base.push(plugin);
+          }
+        }
+      }
+      ("rules", Value::Object(base), Value::Object(rules)) => merge_rules(base, rules),
+      ("overrides" | "ignorePatterns", Value::Array(base), Value::Array(items)) => {
+        base.extend(items)
+      }
+      ("categories" | "env" | "globals" | "settings", base, value) => deep_merge(base, value),
+      (_, base, value) => *base = value,
+    }
+  }
+}
+
+/// 合并规则配置
+///
+/// - `no-debugger` 与 `eslint/no-debugger` 视为同一条规则，后者会被替换
+/// - 只给出严重级别时，保留原有的规则选项
+pub(crate) fn merge_rules(rules: &mut Map<String, Value>, overrides: Map<String, Value>) {
+  for (name, value) in overrides {
+    let existing = rules
+      .remove(&name)
+      .or_else(|| rule_alias(&name).and_then(|alias| rules.remove(&alias)));
+
+    let value = match (existing, value) {
+      (Some(Value::Array(mut existing)), value @ (Value::String(_) | Value::Number(_)))
+        if !existing.is_empty() =>
+      {
+        existing[0] = value;
+        Value::Array(existing)
+      }
+      (_, value) => value,
+    };
+
+    rules.insert(name, value);
+  }
+}
+
+/// 规则的另一种写法：`eslint/no-debugger` <=> `no-debugger`，其它插件的规则没有别名
+pub(crate) fn rule_alias(name: &str) -> Option<String> {
+  match name.strip_prefix("eslint/") {
+    Some(short) => Some(short.to_string()),
+    None if !name.contains('/') => Some(format!("eslint/{}", name)),
+    None => None,
+  }
+}
+
+fn deep_merge(base: &mut Value, value: Value) {
+  match (base, value) {
+    (Value::Object(base), Value::Object(value)) => {
+      for (key, value) in value {
+        deep_merge(base.entry(key).or_insert(Value::Null), value);
+      }
+    }
+    (base, value) => *base = value,
+  }
+}
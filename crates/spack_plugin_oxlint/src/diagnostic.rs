@@ -0,0 +1,186 @@
+use oxc::diagnostics::{LabeledSpan, OxcDiagnostic, Severity};
+use oxc_linter::{DisableDirectives, Message, PossibleFixes, RuleCommentType};
+use rspack_core::ModuleIdentifier;
+use rspack_error::Diagnostic;
+use rustc_hash::FxHashSet;
+
+use crate::overlay::CODE_FRAME_CONTEXT_LINES;
+
+/// 获取 lint 消息在源码中的位置（起始偏移量, 结束偏移量）
+///
+/// 优先使用第一个 label 的位置，没有 label 的消息（如 `max-lines`）定位到文件开头
+pub fn message_span(message: &Message) -> (usize, usize) {
+  message
+    .error
+    .labels
+    .as_ref()
+    .and_then(|labels| labels.first())
+    .map(|label| (label.offset(), label.offset() + label.len()))
+    .unwrap_or((0, 0))
+}
+
+/// 未使用的 disable 指令诊断的规则名
+///
+/// oxlint 报告这类诊断时不带规则编码，这里给它一个单独的规则名，便于在输出、报告和 baseline 中与其它诊断区分
+pub const UNUSED_DIRECTIVE_RULE_ID: &str = "unused-disable-directive";
+
+/// 未使用的指令诊断的规则编码 scope
+const UNUSED_DIRECTIVE_SCOPE: &str = "oxlint";
+
+/// 给未使用的 `eslint-disable` / `eslint-enable` 指令的诊断加上规则编码
+///
+/// 按 linter 返回的指令识别：不带规则编码、且位置与某个未使用的指令（或指令中未使用的规则名）一致的消息
+pub fn mark_unused_directives(
+  messages: Vec<Message>,
+  directives: Option<&DisableDirectives>,
+) -> Vec<Message> {
+  let Some(directives) = directives else {
+    return messages;
+  };
+
+  let spans = directives
+    .collect_unused_disable_comments()
+    .into_iter()
+    .flat_map(|comment| match &comment.r#type {
+      RuleCommentType::All => vec![comment.span],
+      RuleCommentType::Single(rules) => rules.iter().map(|rule| rule.name_span).collect(),
+    })
+    .chain(
+      directives
+        .unused_enable_comments()
+        .iter()
+        .map(|(_, span)| *span),
+    )
+    .map(|span| (span.start as usize, span.end as usize))
+    .collect::<FxHashSet<_>>();
+
+  if spans.is_empty() {
+    return messages;
+  }
+
+  messages
+    .into_iter()
+    .map(|mut message| {
+      if !message.error.code.is_some() && spans.contains(&message_span(&message)) {
+        message.error = message
+          .error
+          .with_error_code(UNUSED_DIRECTIVE_SCOPE, UNUSED_DIRECTIVE_RULE_ID);
+      }
+      message
+    })
+    .collect()
+}
+
+/// 是否是未使用的 `eslint-disable` / `eslint-enable` 指令的诊断（由 `mark_unused_directives` 标记）
+pub fn is_unused_directive(message: &Message) -> bool {
+  let code = &message.error.code;
+
+  code.scope.as_deref() == Some(UNUSED_DIRECTIVE_SCOPE)
+    && code.number.as_deref() == Some(UNUSED_DIRECTIVE_RULE_ID)
+}
+
+/// 语法错误诊断的规则名
+pub const SYNTAX_ERROR_RULE_ID: &str = "syntax-error";
+
+/// 把解析器/语义分析报告的语法错误转换为 lint 消息
+///
+/// - 总是作为错误级别，受 `fail_on_error` 控制
+/// - `offset` 为代码块在原文件中的偏移量（Vue 等单文件组件中的 `<script>`），label 的位置会映射回原文件
+pub fn syntax_error_message(error: OxcDiagnostic, offset: u32) -> Message {
+  let labels = error
+    .labels
+    .as_ref()
+    .map(|labels| {
+      labels
+        .iter()
+        .map(|label| {
+          LabeledSpan::new(
+            label.label().map(|l| l.to_string()),
+            label.offset() + offset as usize,
+            label.len(),
+          )
+        })
+        .collect::<Vec<_>>()
+    })
+    .unwrap_or_default();
+
+  let error = error
+    .with_severity(Severity::Error)
+    .with_error_code("oxc", SYNTAX_ERROR_RULE_ID)
+    .with_labels(labels);
+
+  Message::new(error, PossibleFixes::None)
+}
+
+/// 获取 lint 消息的规则编码，如 `eslint(no-debugger)`
+pub fn message_code(message: &Message) -> Option<String> {
+  let code = &message.error.code;
+  code.is_some().then(|| code.to_string())
+}
+
+/// 获取 lint 消息的规则名，如 `eslint/no-debugger`
+pub fn message_rule_id(message: &Message) -> Option<String> {
+  if is_unused_directive(message) {
+    return Some(UNUSED_DIRECTIVE_RULE_ID.to_string());
+  }
+
+  let code = &message.error.code;
+
+  match (&code.scope, &code.number) {
+    (Some(scope), Some(number)) => Some(format!("{}/{}", scope, number)),
+    (None, Some(number)) => Some(number.to_string()),
+    _ => None,
+  }
+}
+
+/// 把 oxlint 的 `Message` 转换为 rspack 的 `Diagnostic`
+///
+/// - 携带文件、源码位置（由 rspack 计算行列号并渲染 code frame）、规则编码和帮助信息
+/// - `as_error` 为 false 时，错误级别的消息会降级为警告，不阻塞构建
+pub fn to_rspack_diagnostic(
+  path: &str,
+  source_code: &str,
+  message: &Message,
+  module_identifier: Option<ModuleIdentifier>,
+  as_error: bool,
+) -> Diagnostic {
+  let (start, end) = message_span(message);
+
+  let label = message
+    .error
+    .labels
+    .as_ref()
+    .and_then(|labels| labels.first())
+    .and_then(|label| label.label())
+    .unwrap_or(message.error.message.as_ref())
+    .to_string();
+
+  let text = match message_code(message) {
+    Some(code) => format!("{}: {}", code, message.error.message),
+    None => message.error.message.to_string(),
+  };
+
+  // rspack 的诊断持有自己的源码副本，只复制到 code frame 的最后一行，之后的内容不会展示
+  let frame_end = source_code
+    .get(end..)
+    .and_then(|rest| rest.match_indices('\n').nth(CODE_FRAME_CONTEXT_LINES))
+    .map_or(source_code.len(), |(i, _)| end + i);
+
+  let mut error = rspack_error::Error::from_string(
+    Some(source_code[..frame_end].to_string()),
+    start,
+    end,
+    label,
+    text,
+  );
+
+  error.help = message.error.help.as_ref().map(|help| help.to_string());
+
+  if !as_error || message.error.severity != Severity::Error {
+    error.severity = rspack_error::Severity::Warning;
+  }
+
+  Diagnostic::from(error)
+    .with_file(Some(path.into()))
+    .with_module_identifier(module_identifier)
+}
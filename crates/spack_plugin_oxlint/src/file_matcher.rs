@@ -0,0 +1,128 @@
+use std::path::{Path, PathBuf};
+
+use ignore::{
+  WalkBuilder,
+  gitignore::{Gitignore, GitignoreBuilder},
+  overrides::{Override, OverrideBuilder},
+};
+
+/// 自定义忽略文件名，语法与 `.gitignore` 相同
+pub const OXLINT_IGNORE_FILE_NAME: &str = ".oxlintignore";
+
+/// 默认包含的文件
+const DEFAULT_INCLUDE: &[&str] = &[
+  "*.js", "*.jsx", "*.ts", "*.tsx", "*.mjs", "*.cjs", "*.cts", "*.mts", "*.vue", "*.svelte",
+  "*.astro",
+];
+
+/// 总是排除的文件和目录
+const DEFAULT_EXCLUDE: &[&str] = &[
+  "*.d.ts",
+  "*.min.js",
+  "node_modules/**",
+  "**/.lego/**",
+  "**/node_modules/**",
+  "dist/**",
+  "build/**",
+  "coverage/**",
+  ".git/**",
+];
+
+/// 需要 lint 的文件匹配器
+///
+/// - `include`/`exclude` 为 glob，相对路径基于 `root`（compilation 的 context）
+/// - 同时遵循 `root` 下的 `.gitignore`、`.oxlintignore` 以及配置中的 `ignorePatterns`
+/// - 只在首次编译时构建一次，`succeed_module` 中直接复用
+#[derive(Debug)]
+pub struct FileMatcher {
+  root: PathBuf,
+  overrides: Override,
+  ignore: Gitignore,
+}
+
+impl FileMatcher {
+  pub fn new(
+    root: impl AsRef<Path>,
+    include: &[String],
+    exclude: &[String],
+    ignore_patterns: &[String],
+  ) -> Result<Self, ignore::Error> {
+    let root = root.as_ref().to_path_buf();
+
+    let mut overrides = OverrideBuilder::new(&root);
+
+    if include.is_empty() {
+      for glob in DEFAULT_INCLUDE {
+        overrides.add(glob)?;
+      }
+    } else {
+      for glob in include {
+        overrides.add(glob)?;
+      }
+    }
+
+    let excludes = DEFAULT_EXCLUDE
+      .iter()
+      .copied()
+      .chain(exclude.iter().map(String::as_str))
+      .chain(ignore_patterns.iter().map(String::as_str));
+
+    for glob in excludes {
+      overrides.add(&format!("!{}", glob))?;
+    }
+
+    let overrides = overrides.build()?;
+
+    let mut ignore = GitignoreBuilder::new(&root);
+
+    for file_name in [".gitignore", OXLINT_IGNORE_FILE_NAME] {
+      let file = root.join(file_name);
+      if file.exists()
+        && let Some(e) = ignore.add(file)
+      {
+        return Err(e);
+      }
+    }
+
+    let ignore = ignore.build()?;
+
+    Ok(Self {
+      root,
+      overrides,
+      ignore,
+    })
+  }
+
+  /// 判断单个文件是否需要 lint
+  pub fn is_match(&self, path: impl AsRef<Path>) -> bool {
+    let path = path.as_ref();
+
+    if !self.overrides.matched(path, false).is_whitelist() {
+      return false;
+    }
+
+    // Gitignore 只能匹配 root 下的文件
+    if path.starts_with(&self.root)
+      && self
+        .ignore
+        .matched_path_or_any_parents(path, false)
+        .is_ignore()
+    {
+      return false;
+    }
+
+    true
+  }
+
+  /// 遍历 root 下所有需要 lint 的文件（遵循嵌套的 `.gitignore` 和 `.oxlintignore`）
+  pub fn walk(&self) -> Vec<PathBuf> {
+    WalkBuilder::new(&self.root)
+      .overrides(self.overrides.clone())
+      .add_custom_ignore_filename(OXLINT_IGNORE_FILE_NAME)
+      .build()
+      .filter_map(|e| e.ok())
+      .filter(|e| e.file_type().map_or(false, |ft| ft.is_file()))
+      .map(|e| e.into_path())
+      .collect()
+  }
+}
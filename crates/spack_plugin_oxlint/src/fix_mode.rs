@@ -0,0 +1,35 @@
+use oxc_linter::FixKind;
+use strum_macros::EnumString;
+
+/// 自动修复模式
+///
+/// - `none`: 不修复（默认）
+/// - `safe`: 只应用安全修复
+/// - `suggestions`: 应用安全修复和建议修复
+/// - `dangerous`: 应用所有修复（包括可能改变语义的危险修复）
+#[derive(EnumString, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[strum(serialize_all = "camelCase")]
+pub enum FixMode {
+  #[default]
+  None,
+  Safe,
+  Suggestions,
+  Dangerous,
+}
+
+impl FixMode {
+  pub fn is_enabled(&self) -> bool {
+    !matches!(self, FixMode::None)
+  }
+}
+
+impl From<FixMode> for FixKind {
+  fn from(value: FixMode) -> Self {
+    match value {
+      FixMode::None => FixKind::None,
+      FixMode::Safe => FixKind::SafeFix,
+      FixMode::Suggestions => FixKind::SafeFixOrSuggestion,
+      FixMode::Dangerous => FixKind::DangerousFixOrSuggestion,
+    }
+  }
+}
@@ -0,0 +1,146 @@
+use std::path::Path;
+
+use oxc_linter::FrameworkFlags;
+use serde::Serialize;
+use serde_json::Value;
+use strum_macros::EnumString;
+
+/// 项目使用的框架，影响 linter 的框架提示（如 JSX 的处理）和默认开启的插件
+#[derive(EnumString, Debug, Clone, Copy, PartialEq, Eq)]
+#[strum(serialize_all = "camelCase")]
+pub enum Framework {
+  React,
+  Vue,
+  Next,
+  Jest,
+  Vitest,
+}
+
+/// oxlint 插件，序列化为配置中 `plugins` 的名称
+#[derive(EnumString, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[strum(serialize_all = "kebab-case")]
+#[serde(rename_all = "kebab-case")]
+pub enum LintPlugin {
+  Eslint,
+  Typescript,
+  Unicorn,
+  React,
+  Oxc,
+  Import,
+  JsxA11y,
+  Jest,
+  Vitest,
+  Promise,
+  Node,
+  Nextjs,
+}
+
+/// 不依赖框架、默认开启的插件
+const DEFAULT_PLUGINS: &[LintPlugin] = &[
+  LintPlugin::Eslint,
+  LintPlugin::Typescript,
+  LintPlugin::Unicorn,
+  LintPlugin::Oxc,
+  LintPlugin::Import,
+];
+
+/// package.json 中的依赖名 -> 框架
+const FRAMEWORK_DEPENDENCIES: &[(&str, Framework)] = &[
+  ("react", Framework::React),
+  ("vue", Framework::Vue),
+  ("next", Framework::Next),
+  ("jest", Framework::Jest),
+  ("vitest", Framework::Vitest),
+];
+
+impl Framework {
+  /// 该框架需要额外开启的插件
+  fn plugins(&self) -> &'static [LintPlugin] {
+    match self {
+      Framework::React => &[LintPlugin::React],
+      Framework::Vue => &[],
+      Framework::Next => &[LintPlugin::React, LintPlugin::Nextjs],
+      Framework::Jest => &[LintPlugin::Jest],
+      Framework::Vitest => &[LintPlugin::Vitest],
+    }
+  }
+}
+
+impl From<Framework> for FrameworkFlags {
+  fn from(value: Framework) -> Self {
+    match value {
+      Framework::React => FrameworkFlags::React,
+      Framework::Vue => FrameworkFlags::Vue,
+      Framework::Next => FrameworkFlags::Next,
+      Framework::Jest => FrameworkFlags::Jest,
+      Framework::Vitest => FrameworkFlags::Vitest,
+    }
+  }
+}
+
+/// 根据 `dir` 下 package.json 的 dependencies / devDependencies / peerDependencies 检测使用的框架
+///
+/// package.json 不存在或无法解析时返回空列表
+pub fn detect_frameworks(dir: &Path) -> Vec<Framework> {
+  let Some(package_json) = std::fs::read_to_string(dir.join("package.json"))
+    .ok()
+    .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+  else {
+    return vec![];
+  };
+
+  let has_dependency = |name: &str| {
+    ["dependencies", "devDependencies", "peerDependencies"]
+      .iter()
+      .any(|field| package_json[field].get(name).is_some())
+  };
+
+  FRAMEWORK_DEPENDENCIES
+    .iter()
+    .filter(|(name, _)| has_dependency(name))
+    .map(|(_, framework)| *framework)
+    .collect()
+}
+
+/// 框架对应的 linter 框架提示
+pub fn framework_flags(frameworks: &[Framework]) -> FrameworkFlags {
+  frameworks
+    .iter()
+    .fold(FrameworkFlags::empty(), |flags, framework| {
+      flags | FrameworkFlags::from(*framework)
+    })
+}
+
+/// 默认插件列表：通用插件 + 框架对应的插件（去重，保持顺序）
+pub fn default_plugins(frameworks: &[Framework]) -> Vec<LintPlugin> {
+  let mut plugins = DEFAULT_PLUGINS.to_vec();
+
+  for plugin in frameworks.iter().flat_map(|framework| framework.plugins()) {
+    if !plugins.contains(plugin) {
+      plugins.push(*plugin);
+    }
+  }
+
+  plugins
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_default_plugins() {
+    assert_eq!(default_plugins(&[]), DEFAULT_PLUGINS.to_vec());
+
+    let plugins = default_plugins(&[Framework::React, Framework::Next, Framework::Vitest]);
+    assert_eq!(
+      &plugins[DEFAULT_PLUGINS.len()..],
+      &[LintPlugin::React, LintPlugin::Nextjs, LintPlugin::Vitest]
+    );
+
+    assert_eq!(
+      serde_json::to_value(LintPlugin::JsxA11y).unwrap(),
+      Value::String("jsx-a11y".to_string())
+    );
+  }
+}
@@ -1,14 +1,46 @@
 #![recursion_limit = "1024"]
+mod baseline;
+mod changed_files;
 mod config;
+mod config_merge;
+mod diagnostic;
 mod environments;
+mod file_matcher;
+mod fix_mode;
+mod framework;
 mod lint_cache;
+mod lint_message;
 mod lint_runner;
+mod module_record_store;
+mod overlay;
+mod persistent_cache;
 mod plugin;
+mod preset;
+mod report;
+mod resp;
 mod restricted;
+mod stats;
+mod template;
+mod unused_directives;
 
+pub use baseline::*;
+pub use changed_files::*;
 pub use config::*;
 pub use environments::*;
+pub use file_matcher::*;
+pub use fix_mode::*;
+pub use framework::*;
 pub use lint_cache::*;
+pub use lint_message::*;
 pub use lint_runner::*;
+pub use module_record_store::*;
+pub use overlay::*;
+pub use persistent_cache::*;
 pub use plugin::*;
+pub use preset::*;
+pub use report::*;
+pub use resp::*;
 pub use restricted::*;
+pub use stats::*;
+pub use template::*;
+pub use unused_directives::*;
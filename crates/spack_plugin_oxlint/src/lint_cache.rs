@@ -1,20 +1,31 @@
-use std::sync::{
-  Arc,
-  atomic::{AtomicBool, AtomicUsize, Ordering},
+use std::{
+  path::Path,
+  sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+  },
 };
 
 use dashmap::{DashMap, DashSet};
-use oxc::diagnostics;
 use oxc_linter::Message;
 
+use crate::SourceExcerpt;
+
+/// 单个文件的 lint 缓存条目
+#[derive(Debug, Clone)]
+pub struct LintEntry {
+  /// 文件的源码摘录（行索引和消息附近的行），用于生成带位置信息的诊断，不持有完整源码
+  pub source: SourceExcerpt,
+  /// 该文件的所有 lint 错误/警告消息
+  pub messages: Vec<Message>,
+}
+
 /// Lint 缓存管理器（优化版）
 ///
 /// 负责管理三个层次的缓存和状态：
 /// 1. **初始化标志** (`initialized`): 标识插件是否是首次运行（应用级别，整个生命周期只初始化一次）
 /// 2. **本轮已检查文件集** (`linted_files`): 跟踪当前编译周期内已经 lint 过的文件（周期级别，每次 compilation 开始时清空）
 /// 3. **Lint 结果缓存** (`cache`): 存储所有文件的 lint 错误信息（持久级别，跨编译周期保持）
-/// 4. **错误计数器** (`error_count`): 原子计数器，实时维护总错误数（O(1) 查询）
-/// 5. **警告计数器** (`warning_count`): 原子计数器，实时维护总警告数（O(1) 查询）
 ///
 /// ## 性能优化
 ///
@@ -23,11 +34,6 @@ use oxc_linter::Message;
 /// - **细粒度锁**: DashMap 内部使用分片锁（shard），不同分片可以并发写入
 /// - **更高吞吐**: 在高并发场景下性能显著优于 Mutex<HashMap>
 ///
-/// ### 使用 AtomicUsize 维护错误计数
-/// - **O(1) 查询**: `get_error_count()` 从 O(n) 遍历降至 O(1) 原子读
-/// - **无锁操作**: 读取计数无需加锁，零开销
-/// - **实时更新**: 在 `insert_cache`/`remove_from_cache` 时同步更新计数
-///
 /// ### 使用 DashSet 替代 DashMap<String, ()>
 /// - **语义清晰**: `DashSet<String>` 明确表达集合语义，不需要 `()` 占位值
 /// - **API 简洁**: `insert(key)` 直接返回 bool，相比 `insert(key, ()).is_none()` 更直观
@@ -47,15 +53,15 @@ use oxc_linter::Message;
 ///    ├─> 遍历项目所有文件
 ///    │   ├─> mark_files_as_linted() 批量标记文件为已检查
 ///    │   ├─> 逐个调用 lint_runner.lint()
-///    │   └─> insert_cache() 存储 lint 结果（自动更新计数器）
-///    └─> 不在此处读取错误计数（等待 finish_modules）
+///    │   └─> insert_cache() 存储 lint 结果
+///    └─> 不在此处报告诊断（等待 finish_modules）
 ///
 /// 2. succeed_module hook 触发（每个模块编译成功后）
 ///    ├─> try_mark_as_linted() 检查并标记文件
 ///    └─> 返回 false（在步骤1已标记）→ 跳过，避免重复 lint
 ///
 /// 3. finish_modules hook 触发（所有模块处理完成后）
-///    └─> get_error_count() O(1) 读取最终计数器
+///    └─> 遍历 cache 生成本轮的诊断
 /// ```
 ///
 /// ### 热更新流程（mark_as_initialized_once() = false）
@@ -63,24 +69,25 @@ use oxc_linter::Message;
 /// 1. this_compilation hook 触发（文件变更后）
 ///    ├─> mark_as_initialized_once() 返回 false（非首次）
 ///    ├─> clear_linted_files() 清空 linted_files（开启新周期）
+///    ├─> remove_from_cache() / remove_missing_files() 移除被删除或重命名文件的结果
 ///    └─> 不执行全量 lint（跳过遍历文件步骤）
 ///
 /// 2. succeed_module hook 触发（变更的模块重新编译）
 ///    ├─> try_mark_as_linted() 原子性地检查并标记
 ///    │   └─> 返回 true（首次标记）→ 执行 lint
 ///    ├─> 执行 lint_runner.lint() 检查该文件
-///    └─> 更新 cache（自动更新计数器）
-///        ├─> 有错误: insert_cache() → 计数器 +new -old
-///        └─> 无错误: remove_from_cache() → 计数器 -old
+///    └─> 更新 cache
+///        ├─> 有错误: insert_cache() 替换旧结果
+///        └─> 无错误: remove_from_cache() 移除旧结果
 ///
 /// 3. finish_modules hook 触发（所有模块处理完成后）
-///    └─> get_error_count() O(1) 读取最终计数器（准确反映本轮结果）
+///    └─> 遍历 cache 生成本轮的诊断（准确反映本轮结果）
 /// ```
 ///
 /// ## 并发安全与性能
 ///
 /// - **DashMap/DashSet**: 无锁并发读，细粒度写锁，适合高并发场景
-/// - **AtomicBool/AtomicUsize**: CPU 级别的原子操作，无需系统锁
+/// - **AtomicBool**: CPU 级别的原子操作，无需系统锁
 /// - **原子 CAS**: `try_mark_as_linted()` 使用 DashSet 的原子 insert 防止竞态条件
 #[derive(Debug)]
 pub struct LintCache {
@@ -101,30 +108,30 @@ pub struct LintCache {
   /// **优化**: DashSet 支持无锁并发读，细粒度写锁
   linted_files: Arc<DashSet<String>>,
 
+  /// 当前编译周期跳过 lint 的文件（周期级别）
+  ///
+  /// - 只 lint 变更文件时，未变更的文件不需要 lint，也不会被 `succeed_module` 再 lint
+  /// - 与 `linted_files` 分开记录，不算作已 lint 的文件（不影响 baseline 和 unreachable 报告）
+  skipped_files: Arc<DashSet<String>>,
+
   /// Lint 结果缓存（持久级别）- 使用 DashMap 替代 Mutex<HashMap>
   ///
   /// - 键: 文件路径
-  /// - 值: 该文件的所有 lint 错误/警告消息
-  /// - 跨编译周期保持，用于快速统计总错误数
+  /// - 值: 该文件的源码和所有 lint 错误/警告消息
+  /// - 跨编译周期保持，每轮编译结束时用于生成诊断
   ///
   /// **优化**: DashMap 提供无锁并发访问，性能显著优于 Mutex<HashMap>
-  cache: Arc<DashMap<String, Vec<Message>>>,
+  cache: Arc<DashMap<String, LintEntry>>,
 
-  /// 总错误数计数器（实时维护）
+  /// 本轮编译中被自动修复并写回磁盘的文件（周期级别）
   ///
-  /// - 在 `insert_cache`/`remove_from_cache` 时同步更新
-  /// - `get_error_count()` 直接读取，O(1) 时间复杂度
-  ///
-  /// **优化**: 原子计数器，无需遍历所有文件，避免锁竞争
-  error_count: Arc<AtomicUsize>,
+  /// - 在 `finish_modules` 中通过 `take_fixed_files()` 读取并清空
+  fixed_files: Arc<DashSet<String>>,
 
-  /// 总警告数计数器（实时维护）
-  ///
-  /// - 在 `insert_cache`/`remove_from_cache` 时同步更新
-  /// - `get_warning_count()` 直接读取，O(1) 时间复杂度
+  /// 本轮 lint 过程中产生的警告（周期级别）
   ///
-  /// **优化**: 原子计数器，无需遍历所有文件，避免锁竞争
-  warning_count: Arc<AtomicUsize>,
+  /// - 在 `finish_modules` 中通过 `take_warnings()` 读取并清空
+  warnings: Arc<DashSet<String>>,
 }
 
 impl LintCache {
@@ -133,9 +140,10 @@ impl LintCache {
     Self {
       initialized: Arc::new(AtomicBool::new(false)),
       linted_files: Arc::new(DashSet::new()),
+      skipped_files: Arc::new(DashSet::new()),
       cache: Arc::new(DashMap::new()),
-      error_count: Arc::new(AtomicUsize::new(0)),
-      warning_count: Arc::new(AtomicUsize::new(0)),
+      fixed_files: Arc::new(DashSet::new()),
+      warnings: Arc::new(DashSet::new()),
     }
   }
 
@@ -200,6 +208,7 @@ impl LintCache {
   /// **性能**: DashMap 的 clear() 是分片清空，比 Mutex<HashSet> 更高效
   pub fn clear_linted_files(&self) {
     self.linted_files.clear();
+    self.skipped_files.clear();
   }
 
   /// 原子性地尝试标记文件为已检查
@@ -240,7 +249,7 @@ impl LintCache {
     // DashSet::insert 返回 bool
     // true 表示首次插入（需要 lint）
     // false 表示已存在（跳过 lint）
-    self.linted_files.insert(path)
+    !self.skipped_files.contains(&path) && self.linted_files.insert(path)
   }
 
   /// 批量标记多个文件为已检查
@@ -256,161 +265,103 @@ impl LintCache {
     }
   }
 
-  /// 将文件的 lint 结果存入缓存，并更新错误和警告计数器
+  /// 批量标记本轮跳过 lint 的文件（只 lint 变更文件时未变更的文件）
+  pub fn mark_files_as_skipped(&self, files: &[String]) {
+    for file in files {
+      self.skipped_files.insert(file.clone());
+    }
+  }
+
+  /// 将文件的 lint 结果存入缓存，替换该文件之前的结果
   ///
   /// **时机**: lint 完成后，发现有错误/警告时调用
   ///
-  /// **作用**:
-  /// - 持久化 lint 结果
-  /// - 同步更新 `error_count` 和 `warning_count` 计数器（原子操作）
+  /// **性能**: DashMap 插入: 细粒度锁，只锁定单个分片
+  pub fn insert_cache(&self, path: String, entry: LintEntry) {
+    self.cache.insert(path, entry);
+  }
+
+  /// 从缓存中移除文件的 lint 结果
   ///
-  /// **实现细节**:
-  /// 1. 计算新消息中的错误和警告数量
-  /// 2. 插入新消息，获取旧消息（如果有）
-  /// 3. 如果有旧消息，计算旧错误和警告数量
-  /// 4. 更新计数器：`+新数量 -旧数量`
+  /// **时机**: 热更新中 lint 某文件后，发现无错误时调用
   ///
-  /// **性能**:
-  /// - DashMap 插入: 细粒度锁，只锁定单个分片
-  /// - 原子计数: fetch_add/fetch_sub 无需额外锁
-  pub fn insert_cache(&self, path: String, messages: Vec<Message>) {
-    // 计算新消息中的错误和警告数量
-    let new_error_count = messages
-      .iter()
-      .filter(|m| m.error.severity == diagnostics::Severity::Error)
-      .count();
-    let new_warning_count = messages
-      .iter()
-      .filter(|m| m.error.severity == diagnostics::Severity::Warning)
-      .count();
+  /// **作用**: 清除该文件之前的旧错误和警告（文件已修复）
+  pub fn remove_from_cache(&self, path: &str) {
+    self.cache.remove(path);
+  }
 
-    // 插入新消息，获取旧消息
-    if let Some(old_messages) = self.cache.insert(path, messages) {
-      // 有旧消息，计算旧错误和警告数量
-      let old_error_count = old_messages
-        .iter()
-        .filter(|m| m.error.severity == diagnostics::Severity::Error)
-        .count();
-      let old_warning_count = old_messages
-        .iter()
-        .filter(|m| m.error.severity == diagnostics::Severity::Warning)
-        .count();
+  /// 移除已经不存在的文件（被删除或重命名）的 lint 结果
+  ///
+  /// **时机**: 热更新的 `this_compilation` 中，处理完 rspack 提供的 removed files 之后调用，
+  /// 兜底处理 watcher 没有报告的删除/重命名
+  pub fn remove_missing_files(&self) {
+    let missing_files = self
+      .cache
+      .iter()
+      .filter(|e| !Path::new(e.key()).exists())
+      .map(|e| e.key().clone())
+      .collect::<Vec<_>>();
 
-      // 更新计数器：先加新的，再减旧的（避免中间状态为负数）
-      self
-        .error_count
-        .fetch_add(new_error_count, Ordering::Relaxed);
-      self
-        .error_count
-        .fetch_sub(old_error_count, Ordering::Relaxed);
-      self
-        .warning_count
-        .fetch_add(new_warning_count, Ordering::Relaxed);
-      self
-        .warning_count
-        .fetch_sub(old_warning_count, Ordering::Relaxed);
-    } else {
-      // 没有旧消息，直接加上新错误和警告数
-      self
-        .error_count
-        .fetch_add(new_error_count, Ordering::Relaxed);
-      self
-        .warning_count
-        .fetch_add(new_warning_count, Ordering::Relaxed);
+    for path in missing_files {
+      self.remove_from_cache(&path);
     }
   }
 
-  /// 从缓存中移除文件的 lint 结果，并更新错误和警告计数器
-  ///
-  /// **时机**: 热更新中 lint 某文件后，发现无错误时调用
-  ///
-  /// **作用**: 清除该文件之前的旧错误和警告（文件已修复），同步更新计数器
-  ///
-  /// **性能**: DashMap 的 remove 返回被移除的值，一次操作完成
-  pub fn remove_from_cache(&self, path: &str) {
-    // DashMap 的 remove 返回 Option<(K, V)>
-    if let Some((_, old_messages)) = self.cache.remove(path) {
-      // 计算被移除消息的错误和警告数量
-      let old_error_count = old_messages
-        .iter()
-        .filter(|m| m.error.severity == diagnostics::Severity::Error)
-        .count();
-      let old_warning_count = old_messages
-        .iter()
-        .filter(|m| m.error.severity == diagnostics::Severity::Warning)
-        .count();
+  /// 获取本轮编译中 lint 过的文件（按路径排序）
+  pub fn linted_files(&self) -> Vec<String> {
+    let mut files = self
+      .linted_files
+      .iter()
+      .map(|f| f.key().clone())
+      .collect::<Vec<_>>();
+    files.sort();
+    files
+  }
+
+  /// 记录被自动修复（已写回磁盘）的文件
+  pub fn mark_as_fixed(&self, path: String) {
+    self.fixed_files.insert(path);
+  }
+
+  /// 取出本轮被自动修复的文件列表（按路径排序），并清空记录
+  pub fn take_fixed_files(&self) -> Vec<String> {
+    let mut files = self
+      .fixed_files
+      .iter()
+      .map(|f| f.key().clone())
+      .collect::<Vec<_>>();
+    self.fixed_files.clear();
+    files.sort();
+    files
+  }
 
-      // 从计数器中减去
-      self
-        .error_count
-        .fetch_sub(old_error_count, Ordering::Relaxed);
-      self
-        .warning_count
-        .fetch_sub(old_warning_count, Ordering::Relaxed);
+  /// 记录 lint 过程中产生的警告
+  pub fn add_warnings(&self, warnings: Vec<String>) {
+    for warning in warnings {
+      self.warnings.insert(warning);
     }
   }
 
-  /// 获取总错误数（O(1) 原子读取，无锁）
-  ///
-  /// **时机**: 在 `finish_modules` hook 中调用，生成 rspack diagnostic
-  ///
-  /// **返回**: 错误数量（只统计 `Severity::Error`，不包括警告）
-  ///
-  /// **时序保证**:
-  /// - 在 `finish_modules` 中调用可确保所有 `succeed_module` 已完成
-  /// - 返回值准确反映当前编译周期的最终错误状态
-  ///
-  /// **性能对比**:
-  /// - **旧实现**: O(n) 遍历所有文件的所有消息 + Mutex 锁
-  /// - **新实现**: O(1) 原子读取 + 零锁开销
-  ///
-  /// **实现细节**:
-  /// - 计数器在 `insert_cache`/`remove_from_cache` 时实时维护
-  /// - 读取时只需一次原子 load 操作，无需遍历
-  ///
-  /// **示例**:
-  /// ```rust,no_run
-  /// # use std::sync::Arc;
-  /// # use std::sync::atomic::{AtomicUsize, Ordering};
-  /// # struct LintCache { error_count: Arc<AtomicUsize> }
-  /// # impl LintCache {
-  /// #     fn get_error_count(&self) -> usize {
-  /// #         self.error_count.load(Ordering::Relaxed)
-  /// #     }
-  /// # }
-  /// # let cache = LintCache { error_count: Arc::new(AtomicUsize::new(0)) };
-  /// // 旧实现 (O(n) + 锁):
-  /// // cache.lock().map(|c| c.values().flatten().filter(...).count())
-  ///
-  /// // 新实现 (O(1) + 无锁):
-  /// let count = cache.get_error_count();
-  /// assert_eq!(count, 0);
-  /// ```
-  pub fn get_error_count(&self) -> usize {
-    // 原子读取，使用 Relaxed 顺序（最低开销）
-    // Relaxed: 只保证原子性，不保证与其他操作的顺序
-    // 对于计数器场景足够（最终一致性）
-    self.error_count.load(Ordering::Relaxed)
+  /// 取出本轮 lint 过程中产生的警告（排序后），并清空记录
+  pub fn take_warnings(&self) -> Vec<String> {
+    let mut warnings = self
+      .warnings
+      .iter()
+      .map(|w| w.key().clone())
+      .collect::<Vec<_>>();
+    self.warnings.clear();
+    warnings.sort();
+    warnings
   }
 
-  /// 获取总警告数（O(1) 原子读取，无锁）
-  ///
-  /// **时机**: 在 `finish_modules` hook 中调用，生成 rspack diagnostic
-  ///
-  /// **返回**: 警告数量（只统计 `Severity::Warning`，不包括错误）
-  ///
-  /// **时序保证**:
-  /// - 在 `finish_modules` 中调用可确保所有 `succeed_module` 已完成
-  /// - 返回值准确反映当前编译周期的最终警告状态
-  ///
-  /// **性能对比**:
-  /// - **旧实现**: O(n) 遍历所有文件的所有消息 + Mutex 锁
-  /// - **新实现**: O(1) 原子读取 + 零锁开销
-  ///
-  /// **实现细节**:
-  /// - 计数器在 `insert_cache`/`remove_from_cache` 时实时维护
-  /// - 读取时只需一次原子 load 操作，无需遍历
-  pub fn get_warning_count(&self) -> usize {
-    self.warning_count.load(Ordering::Relaxed)
+  /// 获取所有缓存条目的快照（按路径排序，保证输出顺序稳定）
+  pub fn entries(&self) -> Vec<(String, LintEntry)> {
+    let mut entries = self
+      .cache
+      .iter()
+      .map(|e| (e.key().clone(), e.value().clone()))
+      .collect::<Vec<_>>();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    entries
   }
 }
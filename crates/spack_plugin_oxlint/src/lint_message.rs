@@ -0,0 +1,252 @@
+use std::{
+  collections::{BTreeMap, BTreeSet},
+  sync::Arc,
+};
+
+use oxc::diagnostics::Severity;
+use oxc_linter::Message;
+use serde::Serialize;
+
+use crate::{
+  diagnostic::{message_rule_id, message_span},
+  overlay::CODE_FRAME_CONTEXT_LINES,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LintSeverity {
+  Error,
+  Warning,
+}
+
+/// 与源码无关的 lint 消息（已计算好行列号），用于报告输出
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintMessage {
+  /// 规则名，如 `eslint/no-debugger`
+  pub rule_id: Option<String>,
+  pub severity: LintSeverity,
+  pub message: String,
+  pub help: Option<String>,
+  /// 起始偏移量（字节）
+  pub start: usize,
+  /// 结束偏移量（字节）
+  pub end: usize,
+  /// 起始行号（从 1 开始）
+  pub line: usize,
+  /// 起始列号（从 1 开始）
+  pub column: usize,
+  pub end_line: usize,
+  pub end_column: usize,
+  /// 是否有可用的自动修复
+  pub fixable: bool,
+}
+
+impl LintMessage {
+  pub fn from_message(message: &Message, line_index: &LineIndex) -> Self {
+    let error = &message.error;
+
+    let rule_id = message_rule_id(message);
+
+    let severity = match error.severity {
+      Severity::Error => LintSeverity::Error,
+      Severity::Warning | Severity::Advice => LintSeverity::Warning,
+    };
+
+    let (start, end) = message_span(message);
+    let (line, column) = line_index.line_column(start);
+    let (end_line, end_column) = line_index.line_column(end);
+
+    Self {
+      rule_id,
+      severity,
+      message: error.message.to_string(),
+      help: error.help.as_ref().map(|help| help.to_string()),
+      start,
+      end,
+      line,
+      column,
+      end_line,
+      end_column,
+      fixable: !message.fixes.is_empty(),
+    }
+  }
+
+  /// 批量转换一个文件的所有消息
+  pub fn from_messages(messages: &[Message], source_code: &str) -> Vec<Self> {
+    let line_index = LineIndex::new(source_code);
+    messages
+      .iter()
+      .map(|message| Self::from_message(message, &line_index))
+      .collect()
+  }
+}
+
+/// 源码行索引，用于把字节偏移量转换为行列号
+#[derive(Debug)]
+pub struct LineIndex<'a> {
+  source_code: &'a str,
+  /// 每一行起始位置的字节偏移量
+  line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+  pub fn new(source_code: &'a str) -> Self {
+    let line_starts = std::iter::once(0)
+      .chain(source_code.match_indices('\n').map(|(i, _)| i + 1))
+      .collect();
+
+    Self {
+      source_code,
+      line_starts,
+    }
+  }
+
+  /// 字节偏移量转换为（行号, 列号），均从 1 开始，列号按字符计数
+  pub fn line_column(&self, offset: usize) -> (usize, usize) {
+    let offset = offset.min(self.source_code.len());
+
+    let line = match self.line_starts.binary_search(&offset) {
+      Ok(line) => line,
+      Err(line) => line - 1,
+    };
+
+    let line_start = self.line_starts[line];
+
+    let column = self
+      .source_code
+      .get(line_start..offset)
+      .map_or(offset - line_start, |s| s.chars().count());
+
+    (line + 1, column + 1)
+  }
+}
+
+/// 源码摘录：只保留行索引和 lint 消息附近的行，代替完整源码缓存在 `LintEntry` 中
+///
+/// 生成诊断时用 `to_source` 还原出与原文件等长的源码（未保留的行填充为空格），偏移量和行列号保持不变
+#[derive(Debug, Clone, Default)]
+pub struct SourceExcerpt {
+  /// 源码长度（字节）
+  len: usize,
+  /// 每一行起始位置的字节偏移量
+  line_starts: Vec<u32>,
+  /// 保留的行：起始偏移量 -> 行内容（包含换行符）
+  lines: BTreeMap<usize, Box<str>>,
+}
+
+impl SourceExcerpt {
+  /// 保留每条消息所在的行及其前后 `CODE_FRAME_CONTEXT_LINES` 行（code frame 展示的范围）
+  pub fn new(source_code: &str, messages: &[Message]) -> Self {
+    let line_index = LineIndex::new(source_code);
+    let last_line = line_index.line_starts.len() - 1;
+
+    let mut retained = BTreeSet::new();
+
+    for message in messages {
+      let (start, end) = message_span(message);
+      let (start_line, _) = line_index.line_column(start);
+      let (end_line, _) = line_index.line_column(end);
+
+      let first = (start_line - 1).saturating_sub(CODE_FRAME_CONTEXT_LINES);
+      let last = (end_line - 1 + CODE_FRAME_CONTEXT_LINES).min(last_line);
+
+      retained.extend(first..=last);
+    }
+
+    let lines = retained
+      .into_iter()
+      .map(|line| {
+        let start = line_index.line_starts[line];
+        let end = line_index
+          .line_starts
+          .get(line + 1)
+          .copied()
+          .unwrap_or(source_code.len());
+
+        (start, source_code[start..end].into())
+      })
+      .collect();
+
+    Self {
+      len: source_code.len(),
+      line_starts: line_index
+        .line_starts
+        .iter()
+        .map(|&start| start as u32)
+        .collect(),
+      lines,
+    }
+  }
+
+  /// 还原与原文件等长的源码，未保留的行填充为空格
+  ///
+  /// 每次调用都会重新分配，同一个文件的所有消息应共享一份结果
+  pub fn to_source(&self) -> Arc<str> {
+    let mut bytes = vec![b' '; self.len];
+
+    for &start in self.line_starts.iter().skip(1) {
+      bytes[start as usize - 1] = b'\n';
+    }
+
+    for (&start, line) in &self.lines {
+      bytes[start..start + line.len()].copy_from_slice(line.as_bytes());
+    }
+
+    String::from_utf8(bytes).unwrap_or_default().into()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_line_index() {
+    let source = "const a = 1;\nconst 中文 = 2;\n";
+    let index = LineIndex::new(source);
+
+    assert_eq!(index.line_column(0), (1, 1));
+    assert_eq!(index.line_column(6), (1, 7));
+    assert_eq!(index.line_column(13), (2, 1));
+    // "中文" 每个字符占 3 个字节，列号按字符计数
+    assert_eq!(index.line_column(13 + 6 + 6), (2, 9));
+    assert_eq!(index.line_column(source.len()), (3, 1));
+  }
+
+  #[test]
+  fn test_source_excerpt() {
+    use oxc::{diagnostics::OxcDiagnostic, span::Span};
+    use oxc_linter::PossibleFixes;
+
+    let source = (1..=10)
+      .map(|i| format!("const 变量{} = {};\n", i, i))
+      .collect::<String>();
+
+    // 第 6 行的消息
+    let start = source.match_indices('\n').nth(4).unwrap().0 + 1;
+    let message = Message::new(
+      OxcDiagnostic::warn("test").with_label(Span::new(start as u32, start as u32 + 5)),
+      PossibleFixes::None,
+    );
+
+    let excerpt = SourceExcerpt::new(&source, &[message]);
+    let restored = excerpt.to_source();
+
+    // 长度、行号和偏移量保持不变，只保留第 4 ~ 8 行
+    assert_eq!(restored.len(), source.len());
+    assert_eq!(restored.lines().count(), 10);
+    for (number, (restored_line, line)) in restored.lines().zip(source.lines()).enumerate() {
+      if (3..=7).contains(&number) {
+        assert_eq!(restored_line, line);
+      } else {
+        assert!(restored_line.trim().is_empty());
+      }
+    }
+
+    assert_eq!(
+      LineIndex::new(&restored).line_column(start),
+      LineIndex::new(&source).line_column(start)
+    );
+  }
+}
@@ -1,174 +1,683 @@
 use std::{
+  any::Any,
   panic::{AssertUnwindSafe, catch_unwind},
   path::Path,
-  sync::Arc,
+  sync::{
+    Arc, RwLock,
+    atomic::{AtomicBool, Ordering},
+  },
 };
 
 use oxc::{
   allocator::Allocator,
-  diagnostics::{GraphicalReportHandler, GraphicalTheme, NamedSource},
-  parser::Parser,
+  parser::{ParseOptions, Parser},
   semantic::SemanticBuilder,
   span::SourceType,
 };
 use oxc_linter::{
-  AllowWarnDeny, ConfigStore, ConfigStoreBuilder, ContextSubHost, ExternalPluginStore, FixKind,
-  FrameworkFlags, LintOptions, Linter, Message, Oxlintrc,
+  BuiltinLintPlugins, Config, ConfigStore, ConfigStoreBuilder, ContextSubHost, ExternalPluginStore,
+  Fixer, FrameworkFlags, LintOptions, Linter, Message, ModuleRecord, Oxlintrc,
+  loader::{JavaScriptSource, LINT_PARTIAL_LOADER_EXTENSIONS, PartialLoader},
 };
+use rspack_core::Resolver;
 use rspack_error::Result;
 use rustc_hash::FxHashMap;
 
+use crate::{
+  FixMode, ModuleRecordStore, PersistentCache, ResolvedConfig, TEMPLATE_EXTENSIONS,
+  UnusedDirectives, contains_template_tags, content_hash,
+  diagnostic::{mark_unused_directives, syntax_error_message},
+  extract_inline_scripts,
+};
+
+/// 单个文件的 lint 结果
+#[derive(Debug)]
+pub struct LintResult {
+  /// 剩余（未被自动修复）的 lint 消息
+  pub messages: Vec<Message>,
+  /// 是否应用了自动修复并写回了源文件
+  pub fixed: bool,
+  /// lint 时使用的源码（修复后则为修复后的源码），用于生成带位置信息的诊断
+  pub source_code: Arc<str>,
+  /// 是否直接复用了持久化缓存中的结果
+  pub cached: bool,
+  /// lint 过程中的警告（如处理 disable 指令失败后的降级），由插件作为编译警告报告
+  pub warnings: Vec<String>,
+}
+
 #[derive(Debug)]
 pub struct LintRunner {
-  linter: Arc<Linter>,
-  handler: Arc<GraphicalReportHandler>,
-  show_warning: bool,
+  /// 当前使用的 linter，watch 模式下配置变化时会被替换
+  linter: RwLock<Arc<Linter>>,
+  fix: FixMode,
+  /// 是否已停止自动修复，watch 模式下重新编译时不再改写源文件
+  fix_disabled: AtomicBool,
+  unused_directives: UnusedDirectives,
+  framework_hints: FrameworkFlags,
+  persistent_cache: Option<PersistentCache>,
+  /// 在所有文件间共享的 module record（只在开启 import 插件时使用）
+  module_records: ModuleRecordStore,
+  /// 当前配置是否开启了 import 插件
+  import_plugin: AtomicBool,
 }
 
 impl LintRunner {
-  pub fn new(oxlintrc: Oxlintrc, show_warning: bool) -> Self {
-    // 构建 linter
+  /// 构建 linter，配置无效（如未知的规则或插件）时返回错误
+  pub fn new(
+    config: &ResolvedConfig,
+    fix: FixMode,
+    unused_directives: UnusedDirectives,
+    framework_hints: FrameworkFlags,
+    persistent_cache: Option<PersistentCache>,
+  ) -> Result<Self> {
+    let (linter, import_plugin) =
+      Self::build_linter(config, fix, unused_directives, framework_hints).map_err(|e| {
+        rspack_error::Error::error(format!("Failed to build oxlintrc config: {}", e))
+      })?;
+
+    Ok(Self {
+      linter: RwLock::new(Arc::new(linter)),
+      fix,
+      fix_disabled: AtomicBool::new(false),
+      unused_directives,
+      framework_hints,
+      persistent_cache,
+      module_records: ModuleRecordStore::new(),
+      import_plugin: AtomicBool::new(import_plugin),
+    })
+  }
+
+  /// 构建 linter，同时返回是否开启了 import 插件（任意一个嵌套配置开启即可）
+  fn build_linter(
+    config: &ResolvedConfig,
+    fix: FixMode,
+    unused_directives: UnusedDirectives,
+    framework_hints: FrameworkFlags,
+  ) -> std::result::Result<(Linter, bool), String> {
     let mut external_plugin_store = ExternalPluginStore::default();
-    let config =
-      ConfigStoreBuilder::from_oxlintrc(true, oxlintrc.clone(), None, &mut external_plugin_store)
-        .expect("Failed to build inner oxlintrc config store builder.")
-        .build(&external_plugin_store)
-        .expect("Failed to build oxlintrc config.");
 
-    let linter = Arc::new(Linter::new(
+    let (base_config, mut import_plugin) =
+      build_config(config.oxlintrc.clone(), &mut external_plugin_store)?;
+
+    // 嵌套配置以所在目录为 key，linter 会为每个文件选择离它最近的配置
+    let mut nested_configs = FxHashMap::default();
+
+    for nested in &config.nested {
+      let (nested_config, nested_import_plugin) =
+        build_config(nested.oxlintrc.clone(), &mut external_plugin_store)
+          .map_err(|e| format!("{} (nested config in {:?})", e, nested.dir))?;
+
+      import_plugin |= nested_import_plugin;
+      nested_configs.insert(nested.dir.clone(), nested_config);
+    }
+
+    let linter = Linter::new(
       LintOptions {
-        fix: FixKind::None,
-        framework_hints: FrameworkFlags::React,
-        report_unused_directive: Some(AllowWarnDeny::Deny),
+        fix: fix.into(),
+        framework_hints,
+        report_unused_directive: unused_directives.into(),
       },
-      ConfigStore::new(config, FxHashMap::default(), external_plugin_store),
+      ConfigStore::new(base_config, nested_configs, external_plugin_store),
       None,
-    ));
-
-    // 构建 handler
-    let handler = Arc::new(
-      GraphicalReportHandler::new()
-        .with_links(true)
-        .with_link_display_text("View in editor")
-        .with_theme(GraphicalTheme::unicode()),
     );
 
-    Self {
-      linter,
-      handler,
-      show_warning,
+    Ok((linter, import_plugin))
+  }
+
+  /// 使用新的配置替换 linter（watch 模式下配置文件变化时调用）
+  ///
+  /// 配置无效时返回错误并继续使用原来的 linter；成功时清空持久化缓存
+  pub fn reload(&self, config: &ResolvedConfig) -> std::result::Result<(), String> {
+    let (linter, import_plugin) = Self::build_linter(
+      config,
+      self.fix,
+      self.unused_directives,
+      self.framework_hints,
+    )?;
+
+    if let Some(cache) = &self.persistent_cache {
+      cache.reset(config);
     }
+
+    *self.linter.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(linter);
+    self.import_plugin.store(import_plugin, Ordering::Relaxed);
+
+    Ok(())
   }
 
-  pub async fn lint(&self, resource: impl AsRef<Path>) -> Result<Vec<Message>> {
+  fn current_linter(&self) -> Arc<Linter> {
+    self
+      .linter
+      .read()
+      .unwrap_or_else(|e| e.into_inner())
+      .clone()
+  }
+
+  /// 停止自动修复
+  ///
+  /// 修复会写回源文件，watch 模式下重新编译时继续修复会触发新的编译，所以只在首次编译时修复
+  pub fn disable_fix(&self) {
+    self.fix_disabled.store(true, Ordering::Relaxed);
+  }
+
+  /// 是否允许对该文件执行自动修复（永远不改写 node_modules 下的文件）
+  fn can_fix(&self, path: &Path) -> bool {
+    self.fix.is_enabled()
+      && !self.fix_disabled.load(Ordering::Relaxed)
+      && !path.components().any(|c| c.as_os_str() == "node_modules")
+  }
+
+  pub async fn lint(&self, resource: impl AsRef<Path>) -> Result<LintResult> {
     let path = resource.as_ref();
 
+    let mut source_code = tokio::fs::read_to_string(path).await?;
+
+    let file = path.to_string_lossy();
+
+    // 持久化缓存只以文件自身的内容为 key，跨模块规则的结果依赖其它文件，开启 import 插件时不使用
+    let persistent_cache = self
+      .persistent_cache
+      .as_ref()
+      .filter(|_| !self.import_plugin.load(Ordering::Relaxed));
+
+    // 内容未变化时直接复用持久化缓存中的结果
+    if let Some(cache) = persistent_cache
+      && let Some(messages) = cache.get(&file, &content_hash(&source_code), self.can_fix(path))
+    {
+      return Ok(LintResult {
+        messages,
+        fixed: false,
+        source_code: source_code.into(),
+        cached: true,
+        warnings: vec![],
+      });
+    }
+
+    let module_records = self.load_module_records(path, &source_code).await;
+
+    let mut warnings = vec![];
+
+    let mut messages =
+      self.run_linter(path, &source_code, module_records.as_deref(), &mut warnings)?;
+
+    let mut fixed = false;
+
+    // EJS 标签被替换为占位符，修复可能覆盖到模板语法，包含 EJS 标签的模板不自动修复
+    let is_template_with_tags = path
+      .extension()
+      .and_then(|ext| ext.to_str())
+      .is_some_and(|ext| TEMPLATE_EXTENSIONS.contains(&ext))
+      && contains_template_tags(&source_code);
+
+    if !messages.is_empty() && self.can_fix(path) && !is_template_with_tags {
+      let fix_result = Fixer::new(&source_code, messages).fix();
+
+      messages = fix_result.messages;
+
+      if fix_result.fixed {
+        let fixed_code = fix_result.fixed_code.into_owned();
+
+        tokio::fs::write(path, &fixed_code).await?;
+
+        // 修复后代码位置发生了变化，重新 lint 一次，保证剩余消息的位置与磁盘上的文件一致
+        let module_records = self.load_module_records(path, &fixed_code).await;
+
+        messages = self.run_linter(path, &fixed_code, module_records.as_deref(), &mut warnings)?;
+        source_code = fixed_code;
+        fixed = true;
+      }
+    }
+
+    if let Some(cache) = persistent_cache {
+      cache.insert(file.into_owned(), content_hash(&source_code), &messages);
+    }
+
+    Ok(LintResult {
+      messages,
+      fixed,
+      source_code: source_code.into(),
+      cached: false,
+      warnings,
+    })
+  }
+
+  /// 设置解析 import 使用的 resolver（开启 import 插件时才需要）
+  pub fn set_resolver(&self, resolver: Arc<Resolver>) {
+    self.module_records.set_resolver(resolver);
+  }
+
+  /// 文件被删除或重命名时，移除其 module record 和持久化缓存中的结果
+  pub fn remove_file(&self, path: &Path) {
+    self.module_records.remove(path);
+
+    if let Some(cache) = &self.persistent_cache {
+      cache.remove(&path.to_string_lossy());
+    }
+  }
+
+  /// 开启 import 插件时，构建文件的 module record 并链接其依赖，供跨模块规则使用
+  async fn load_module_records(
+    &self,
+    path: &Path,
+    source_code: &str,
+  ) -> Option<Vec<Arc<ModuleRecord>>> {
+    if !self.import_plugin.load(Ordering::Relaxed) {
+      return None;
+    }
+
+    Some(self.module_records.load(path, source_code).await)
+  }
+
+  /// 把持久化缓存写回磁盘
+  pub fn save_cache(&self) -> std::result::Result<(), String> {
+    match &self.persistent_cache {
+      Some(cache) => cache.save(),
+      None => Ok(()),
+    }
+  }
+
+  fn run_linter(
+    &self,
+    path: &Path,
+    source_code: &str,
+    module_records: Option<&[Arc<ModuleRecord>]>,
+    warnings: &mut Vec<String>,
+  ) -> Result<Vec<Message>> {
+    let linter = self.current_linter();
+
     let allocator = Allocator::default();
 
-    let source_type =
-      SourceType::from_path(&path).map_err(|e| rspack_error::Error::from_error(e))?;
+    let context_sub_hosts =
+      match build_context_sub_hosts(path, source_code, module_records, &allocator)? {
+        ParsedSources::Empty => return Ok(vec![]),
+        ParsedSources::SyntaxErrors(messages) => return Ok(messages),
+        ParsedSources::Parsed(context_sub_hosts) => context_sub_hosts,
+      };
 
-    let source_code = tokio::fs::read_to_string(path).await?;
+    let result = catch_unwind(AssertUnwindSafe(|| {
+      linter.run_with_disable_directives(path, context_sub_hosts, &allocator)
+    }));
 
-    let parse_options = oxc::parser::ParseOptions {
-      parse_regular_expression: true,
-      allow_return_outside_function: false,
-      preserve_parens: true,
-      allow_v8_intrinsics: false,
+    match result {
+      Ok((messages, disable_directives)) => Ok(mark_unused_directives(
+        messages,
+        disable_directives.as_ref(),
+      )),
+      Err(e) => {
+        warnings.push(format!(
+          "Failed to process disable directives for {}, falling back to linting without unused directive reporting: {}",
+          path.display(),
+          panic_message(&*e),
+        ));
+        self.run_linter_without_directives(&linter, path, source_code, module_records, warnings)
+      }
+    }
+  }
+
+  /// 处理 disable 指令时发生 panic 后的降级：关闭未使用指令的报告后重新 lint，
+  /// 保证文件的其它 lint 结果不会丢失
+  fn run_linter_without_directives(
+    &self,
+    linter: &Linter,
+    path: &Path,
+    source_code: &str,
+    module_records: Option<&[Arc<ModuleRecord>]>,
+    warnings: &mut Vec<String>,
+  ) -> Result<Vec<Message>> {
+    let linter = linter.clone().with_report_unused_directives(None);
+
+    // 上一次的 semantic 已经被消费，需要重新解析
+    let allocator = Allocator::default();
+
+    let ParsedSources::Parsed(context_sub_hosts) =
+      build_context_sub_hosts(path, source_code, module_records, &allocator)?
+    else {
+      return Ok(vec![]);
     };
 
-    let parser = Parser::new(&allocator, &source_code, source_type).with_options(parse_options);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+      linter.run(path, context_sub_hosts, &allocator)
+    }));
+
+    Ok(result.unwrap_or_else(|e| {
+      warnings.push(format!(
+        "Failed to lint {}: {}",
+        path.display(),
+        panic_message(&*e)
+      ));
+      vec![]
+    }))
+  }
+}
+
+/// 获取 panic 的信息
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+  payload
+    .downcast_ref::<&str>()
+    .map(|s| s.to_string())
+    .or_else(|| payload.downcast_ref::<String>().cloned())
+    .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+/// 构建单个配置，同时返回是否开启了 import 插件
+fn build_config(
+  oxlintrc: Oxlintrc,
+  external_plugin_store: &mut ExternalPluginStore,
+) -> std::result::Result<(Config, bool), String> {
+  let config_builder =
+    ConfigStoreBuilder::from_oxlintrc(true, oxlintrc, None, external_plugin_store)
+      .map_err(|e| format!("Failed to build oxlintrc config store builder: {:?}", e))?;
+
+  // import 插件的规则（no-cycle、named 等）需要跨文件的 module record
+  let import_plugin = config_builder
+    .plugins()
+    .builtin
+    .contains(BuiltinLintPlugins::IMPORT);
+
+  let config = config_builder
+    .build(external_plugin_store)
+    .map_err(|e| format!("Failed to build oxlintrc config: {:?}", e))?;
+
+  Ok((config, import_plugin))
+}
 
-    let parser_return = parser.parse();
+/// 文件中所有代码块的解析结果
+enum ParsedSources<'a> {
+  /// 没有需要 lint 的代码块
+  Empty,
+  /// 存在语法错误（与 oxlint 命令行一致，有语法错误的文件不再执行 lint 规则）
+  SyntaxErrors(Vec<Message>),
+  Parsed(Vec<ContextSubHost<'a>>),
+}
 
-    if parser_return.panicked {
-      eprintln!("Warning: Failed to parse file: {:?}", path);
-      return Ok(vec![]);
+/// 解析文件中的所有代码块，构建 linter 需要的 `ContextSubHost`
+///
+/// 解析器和语义分析报告的语法错误会转换为 lint 错误，位置映射回原文件
+fn build_context_sub_hosts<'a>(
+  path: &Path,
+  source_code: &'a str,
+  module_records: Option<&[Arc<ModuleRecord>]>,
+  allocator: &'a Allocator,
+) -> Result<ParsedSources<'a>> {
+  let sources = load_sources(path, source_code, allocator)?;
+
+  // 只有与代码块一一对应时才复用已链接依赖的 module record
+  let module_records = module_records.filter(|records| records.len() == sources.len());
+
+  let mut context_sub_hosts = Vec::with_capacity(sources.len());
+  let mut syntax_errors = vec![];
+
+  for (index, source) in sources.into_iter().enumerate() {
+    let parser_return = Parser::new(allocator, source.source_text, source.source_type)
+      .with_options(parse_options())
+      .parse();
+
+    if parser_return.panicked || !parser_return.errors.is_empty() {
+      syntax_errors.extend(
+        parser_return
+          .errors
+          .into_iter()
+          .map(|error| syntax_error_message(error, source.start)),
+      );
+      continue;
     }
 
-    let program = allocator.alloc(&parser_return.program);
+    let program = allocator.alloc(parser_return.program);
 
     let semantic_builder_return = SemanticBuilder::new()
       .with_check_syntax_error(true)
       .with_cfg(true)
       .build(program);
 
+    if !semantic_builder_return.errors.is_empty() {
+      syntax_errors.extend(
+        semantic_builder_return
+          .errors
+          .into_iter()
+          .map(|error| syntax_error_message(error, source.start)),
+      );
+      continue;
+    }
+
     let semantic = semantic_builder_return.semantic;
 
-    let module_record = Arc::new(oxc_linter::ModuleRecord::new(
-      path,
-      &parser_return.module_record,
-      &semantic,
-    ));
+    let module_record = match module_records {
+      Some(records) => records[index].clone(),
+      None => Arc::new(ModuleRecord::new(
+        path,
+        &parser_return.module_record,
+        &semantic,
+      )),
+    };
 
-    let context_sub_hosts = ContextSubHost::new(semantic, module_record, 0);
+    // 代码块在原文件中的偏移量，lint 消息的位置会映射回原文件
+    context_sub_hosts.push(ContextSubHost::new(semantic, module_record, source.start));
+  }
 
-    let result = catch_unwind(AssertUnwindSafe(|| {
-      self
-        .linter
-        .run_with_disable_directives(path, vec![context_sub_hosts], &allocator)
+  if !syntax_errors.is_empty() {
+    return Ok(ParsedSources::SyntaxErrors(syntax_errors));
+  }
+
+  if context_sub_hosts.is_empty() {
+    return Ok(ParsedSources::Empty);
+  }
+
+  Ok(ParsedSources::Parsed(context_sub_hosts))
+}
+
+/// 从文件中提取需要 lint 的 JS/TS 代码块
+///
+/// - 普通 JS/TS 文件：整个文件作为一个代码块
+/// - Vue / Svelte / Astro 单文件组件：提取所有 `<script>`（包括 `<script setup>`）代码块，
+///   并根据 `lang` 确定语言，同时记录代码块在原文件中的偏移量
+/// - HTML / EJS 模板：提取内联的 `<script>` 代码块，其中的 EJS 标签替换为等长的占位符
+pub(crate) fn load_sources<'a>(
+  path: &Path,
+  source_code: &'a str,
+  allocator: &'a Allocator,
+) -> Result<Vec<JavaScriptSource<'a>>> {
+  let ext = path
+    .extension()
+    .and_then(|ext| ext.to_str())
+    .unwrap_or_default();
+
+  if LINT_PARTIAL_LOADER_EXTENSIONS.contains(&ext) {
+    return Ok(PartialLoader::parse(ext, source_code).unwrap_or_default());
+  }
+
+  if TEMPLATE_EXTENSIONS.contains(&ext) {
+    return Ok(extract_inline_scripts(source_code, allocator));
+  }
+
+  let source_type = SourceType::from_path(path).map_err(|e| rspack_error::Error::from_error(e))?;
+
+  Ok(vec![JavaScriptSource::new(source_code, source_type)])
+}
+
+pub(crate) fn parse_options() -> ParseOptions {
+  ParseOptions {
+    parse_regular_expression: true,
+    allow_return_outside_function: false,
+    preserve_parens: true,
+    allow_v8_intrinsics: false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rspack_core::{
+    DependencyCategory, Resolve, ResolveOptionsWithDependencyType, ResolverFactory,
+  };
+  use rspack_fs::NativeFileSystem;
+  use serde_json::json;
+
+  use super::*;
+  use crate::diagnostic::{UNUSED_DIRECTIVE_RULE_ID, message_rule_id};
+
+  fn cycle_messages(messages: &[Message]) -> usize {
+    messages
+      .iter()
+      .filter(|message| message.error.code.number.as_deref() == Some("no-cycle"))
+      .count()
+  }
+
+  #[test]
+  fn test_import_cycle_relint() {
+    let temp_dir = std::env::temp_dir().join("test_oxlint_import_cycle");
+    std::fs::create_dir_all(&temp_dir).unwrap();
+
+    let a = temp_dir.join("a.js");
+    let b = temp_dir.join("b.js");
+    std::fs::write(
+      &a,
+      "import { b } from './b.js';\nexport const a = () => b;\n",
+    )
+    .unwrap();
+    std::fs::write(
+      &b,
+      "import { a } from './a.js';\nexport const b = () => a;\n",
+    )
+    .unwrap();
+
+    let config = ResolvedConfig {
+      oxlintrc: serde_json::from_value(json!({
+        "plugins": ["import"],
+        "rules": { "import/no-cycle": "error" }
+      }))
+      .unwrap(),
+      nested: vec![],
+      files: vec![],
+    };
+
+    let runner = LintRunner::new(
+      &config,
+      FixMode::None,
+      UnusedDirectives::Off,
+      FrameworkFlags::empty(),
+      None,
+    )
+    .unwrap();
+
+    let resolver_factory = ResolverFactory::new(
+      false,
+      Resolve::default(),
+      Arc::new(NativeFileSystem::new(false)),
+    );
+    runner.set_resolver(resolver_factory.get(ResolveOptionsWithDependencyType {
+      resolve_options: None,
+      resolve_to_context: false,
+      dependency_category: DependencyCategory::Esm,
     }));
 
-    let (messages, _disable_directives) = match result {
-      Ok(result) => result,
-      Err(e) => {
-        eprintln!(
-          "Warning: Failed to process disable directives for {:?}, falling back to basic linting: {:?}",
-          &path, e,
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    // 第二轮 lint（watch 模式下的重新 lint）时，依赖方仍然要能通过新的 module record 找到循环
+    for round in 0..2 {
+      for file in [&a, &b] {
+        let result = runtime.block_on(runner.lint(file)).unwrap();
+        assert_eq!(
+          cycle_messages(&result.messages),
+          1,
+          "round {} {:?}",
+          round,
+          file
         );
-        (vec![], None)
       }
+    }
+
+    let _ = std::fs::remove_dir_all(&temp_dir);
+  }
+
+  #[test]
+  fn test_disable_fix() {
+    let temp_dir = std::env::temp_dir().join("test_oxlint_disable_fix");
+    std::fs::create_dir_all(&temp_dir).unwrap();
+
+    let file = temp_dir.join("index.js");
+    let source = "debugger;\nexport const a = 1;\n";
+    std::fs::write(&file, source).unwrap();
+
+    let config = ResolvedConfig {
+      oxlintrc: serde_json::from_value(json!({
+        "rules": { "no-debugger": "error" }
+      }))
+      .unwrap(),
+      nested: vec![],
+      files: vec![],
     };
 
-    let named_source = NamedSource::new(path.to_string_lossy(), source_code.clone());
+    let runner = LintRunner::new(
+      &config,
+      FixMode::Safe,
+      UnusedDirectives::Off,
+      FrameworkFlags::empty(),
+      None,
+    )
+    .unwrap();
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    // 首次编译：修复并写回源文件
+    let result = runtime.block_on(runner.lint(&file)).unwrap();
+    assert!(result.fixed);
+    assert!(result.messages.is_empty());
+    assert_eq!(
+      std::fs::read_to_string(&file).unwrap(),
+      "\nexport const a = 1;\n"
+    );
 
-    if messages.is_empty() {
-      return Ok(vec![]);
-    }
+    // watch 模式下重新编译：只报告，不再改写源文件
+    std::fs::write(&file, source).unwrap();
+    runner.disable_fix();
 
-    for message in messages.clone() {
-      let error = message.error;
+    let result = runtime.block_on(runner.lint(&file)).unwrap();
+    assert!(!result.fixed);
+    assert_eq!(result.messages.len(), 1);
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), source);
 
-      let show = match error.severity {
-        oxc::diagnostics::Severity::Error => true,
-        oxc::diagnostics::Severity::Warning | oxc::diagnostics::Severity::Advice => {
-          self.show_warning
-        }
-      };
+    let _ = std::fs::remove_dir_all(&temp_dir);
+  }
 
-      if !show {
-        continue;
-      }
+  #[test]
+  fn test_unused_directives() {
+    let temp_dir = std::env::temp_dir().join("test_oxlint_unused_directives");
+    std::fs::create_dir_all(&temp_dir).unwrap();
+
+    let file = temp_dir.join("index.js");
+    std::fs::write(
+      &file,
+      "// eslint-disable-next-line no-debugger\nexport const a = 1;\n",
+    )
+    .unwrap();
+
+    let config = ResolvedConfig {
+      oxlintrc: serde_json::from_value(json!({
+        "rules": { "no-debugger": "error" }
+      }))
+      .unwrap(),
+      nested: vec![],
+      files: vec![],
+    };
 
-      let mut output = String::with_capacity(128);
-
-      // let number = error.as_ref().code.number.as_deref().unwrap_or_default();
-
-      // let report = if [
-      //   "max-lines-per-function",
-      //   "max-classes-per-file",
-      //   "max-lines",
-      // ]
-      // .into_iter()
-      // .any(|v| v == number)
-      // {
-      //   let named_source = NamedSource::new(path.to_string_lossy(), "// coding".to_string());
-      //   error
-      //     .with_labels(Vec::<oxc::diagnostics::LabeledSpan>::new())
-      //     .with_source_code(named_source)
-      // } else {
-      //   error.with_source_code(named_source.clone())
-      // };
-
-      let report = error.with_source_code(named_source.clone());
-      self
-        .handler
-        .render_report(&mut output, report.as_ref())
-        .map_err(|e| rspack_error::Error::from_error(e))?;
-
-      eprintln!("{}", output);
-    }
+    let runner = LintRunner::new(
+      &config,
+      FixMode::None,
+      UnusedDirectives::Warn,
+      FrameworkFlags::empty(),
+      None,
+    )
+    .unwrap();
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let result = runtime.block_on(runner.lint(&file)).unwrap();
+
+    assert!(result.warnings.is_empty());
+    assert_eq!(result.messages.len(), 1);
+    assert_eq!(
+      message_rule_id(&result.messages[0]).as_deref(),
+      Some(UNUSED_DIRECTIVE_RULE_ID)
+    );
 
-    return Ok(messages);
+    let _ = std::fs::remove_dir_all(&temp_dir);
   }
 }
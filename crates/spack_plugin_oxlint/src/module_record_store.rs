@@ -0,0 +1,251 @@
+use std::{
+  path::{Path, PathBuf},
+  sync::{Arc, OnceLock},
+};
+
+use dashmap::DashMap;
+use oxc::{
+  allocator::Allocator,
+  parser::Parser,
+  semantic::SemanticBuilder,
+  span::{CompactStr, SourceType},
+};
+use oxc_linter::{ModuleRecord, loader::LINT_PARTIAL_LOADER_EXTENSIONS};
+use rspack_core::{ResolveResult, Resolver};
+use rspack_paths::Utf8Path;
+use rustc_hash::FxHashSet;
+
+use crate::lint_runner::{load_sources, parse_options};
+
+/// 跨文件共享的 module record
+///
+/// oxlint 的 import 插件规则（`no-cycle`、`named`、`namespace` 等）需要通过 `loaded_modules`
+/// 访问被导入模块的 module record。这里使用 rspack 的 resolver 解析 import，
+/// 按需解析（只 parse，不 lint）依赖文件，并把结果在所有文件之间共享
+///
+/// - 被 lint 的文件每次都会重新构建 module record，替换旧的记录；依赖方的 `loaded_modules` 中保存的是弱引用，
+///   替换时会把所有依赖方重新链接到新的记录上，否则依赖方的跨模块规则（如 `no-cycle`）会丢失结果
+/// - 不会加载 node_modules 下的模块
+/// - 开启 import 插件时不使用持久化缓存，每个文件都会重新构建并链接
+#[derive(Debug, Default)]
+pub struct ModuleRecordStore {
+  records: DashMap<PathBuf, Arc<ModuleRecord>>,
+  /// 被依赖的文件 -> 依赖方（文件路径, import 的 specifier）
+  dependents: DashMap<PathBuf, FxHashSet<(PathBuf, CompactStr)>>,
+  resolver: OnceLock<Arc<Resolver>>,
+}
+
+impl ModuleRecordStore {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// 设置 resolver（只在首次编译时设置一次）
+  pub fn set_resolver(&self, resolver: Arc<Resolver>) {
+    let _ = self.resolver.set(resolver);
+  }
+
+  /// 移除文件的 module record 及其依赖关系（文件被删除或重命名时调用）
+  ///
+  /// 同时从其它文件的依赖方中移除，避免重新链接时访问已删除的文件
+  pub fn remove(&self, path: &Path) {
+    self.records.remove(path);
+    self.dependents.remove(path);
+
+    self.dependents.retain(|_, dependents| {
+      dependents.retain(|(dependent, _)| dependent != path);
+      !dependents.is_empty()
+    });
+  }
+
+  /// 构建文件每个代码块的 module record，并递归链接其依赖
+  pub async fn load(&self, path: &Path, source_code: &str) -> Vec<Arc<ModuleRecord>> {
+    let records = build_module_records(path, source_code);
+
+    if let Some(record) = records.first() {
+      self.replace(path, record.clone());
+    }
+
+    self
+      .link(
+        records
+          .iter()
+          .map(|record| (path.to_path_buf(), record.clone()))
+          .collect(),
+      )
+      .await;
+
+    records
+  }
+
+  /// 写入文件的 module record，并把已链接到旧记录的依赖方重新链接到新记录
+  fn replace(&self, path: &Path, record: Arc<ModuleRecord>) {
+    self.records.insert(path.to_path_buf(), record.clone());
+
+    let Some(dependents) = self.dependents.get(path).map(|d| d.clone()) else {
+      return;
+    };
+
+    for (dependent_path, specifier) in dependents {
+      let Some(dependent) = self.records.get(&dependent_path).map(|r| r.clone()) else {
+        continue;
+      };
+
+      if let Ok(mut loaded_modules) = dependent.loaded_modules.write() {
+        loaded_modules.insert(specifier, Arc::downgrade(&record));
+      }
+    }
+  }
+
+  /// 解析 module record 中的 import，把依赖的 module record 写入 `loaded_modules`
+  ///
+  /// 未加载过的依赖会被解析并加入队列，直到整个依赖图都被链接
+  async fn link(&self, mut queue: Vec<(PathBuf, Arc<ModuleRecord>)>) {
+    let Some(resolver) = self.resolver.get() else {
+      return;
+    };
+
+    while let Some((path, record)) = queue.pop() {
+      let Some(dir) = path.parent().and_then(Utf8Path::from_path) else {
+        continue;
+      };
+
+      let specifiers = record.requested_modules.keys().cloned().collect::<Vec<_>>();
+
+      for specifier in specifiers {
+        let Ok(ResolveResult::Resource(resource)) = resolver.resolve(dir, &specifier).await else {
+          continue;
+        };
+
+        let dependency_path = resource.path.into_std_path_buf();
+
+        if !is_module_file(&dependency_path) {
+          continue;
+        }
+
+        let existing = self
+          .records
+          .get(&dependency_path)
+          .map(|record| record.clone());
+
+        let dependency = match existing {
+          Some(dependency) => dependency,
+          None => {
+            let Ok(source_code) = tokio::fs::read_to_string(&dependency_path).await else {
+              continue;
+            };
+
+            let Some(dependency) = build_module_records(&dependency_path, &source_code)
+              .into_iter()
+              .next()
+            else {
+              continue;
+            };
+
+            self.replace(&dependency_path, dependency.clone());
+            queue.push((dependency_path.clone(), dependency.clone()));
+
+            dependency
+          }
+        };
+
+        self
+          .dependents
+          .entry(dependency_path)
+          .or_default()
+          .insert((path.clone(), specifier.clone()));
+
+        if let Ok(mut loaded_modules) = record.loaded_modules.write() {
+          loaded_modules.insert(specifier, Arc::downgrade(&dependency));
+        }
+      }
+    }
+  }
+}
+
+/// 是否为可以构建 module record 的文件（排除 node_modules、样式、图片等资源）
+fn is_module_file(path: &Path) -> bool {
+  if path.components().any(|c| c.as_os_str() == "node_modules") {
+    return false;
+  }
+
+  let is_partial = path
+    .extension()
+    .and_then(|ext| ext.to_str())
+    .is_some_and(|ext| LINT_PARTIAL_LOADER_EXTENSIONS.contains(&ext));
+
+  is_partial || SourceType::from_path(path).is_ok()
+}
+
+/// 只解析（不 lint）文件，构建每个代码块的 module record
+fn build_module_records(path: &Path, source_code: &str) -> Vec<Arc<ModuleRecord>> {
+  let allocator = Allocator::default();
+
+  let Ok(sources) = load_sources(path, source_code, &allocator) else {
+    return vec![];
+  };
+
+  let mut records = Vec::with_capacity(sources.len());
+
+  for source in sources {
+    let parser_return = Parser::new(&allocator, source.source_text, source.source_type)
+      .with_options(parse_options())
+      .parse();
+
+    if parser_return.panicked {
+      return vec![];
+    }
+
+    let program = allocator.alloc(parser_return.program);
+
+    let semantic = SemanticBuilder::new().build(program).semantic;
+
+    records.push(Arc::new(ModuleRecord::new(
+      path,
+      &parser_return.module_record,
+      &semantic,
+    )));
+  }
+
+  records
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_remove() {
+    let store = ModuleRecordStore::new();
+    let (a, b, c) = (
+      PathBuf::from("/src/a.js"),
+      PathBuf::from("/src/b.js"),
+      PathBuf::from("/src/c.js"),
+    );
+
+    // a 依赖 b，b 和 c 依赖 a
+    store.dependents.insert(
+      a.clone(),
+      FxHashSet::from_iter([
+        (b.clone(), CompactStr::from("./a.js")),
+        (c.clone(), CompactStr::from("./a.js")),
+      ]),
+    );
+    store.dependents.insert(
+      b.clone(),
+      FxHashSet::from_iter([(a.clone(), CompactStr::from("./b.js"))]),
+    );
+
+    store.remove(&b);
+
+    assert!(!store.dependents.contains_key(&b));
+    assert_eq!(
+      *store.dependents.get(&a).unwrap(),
+      FxHashSet::from_iter([(c.clone(), CompactStr::from("./a.js"))])
+    );
+
+    // 没有依赖方的文件不再保留
+    store.remove(&c);
+    assert!(store.dependents.is_empty());
+  }
+}
@@ -0,0 +1,306 @@
+use std::{fmt::Write, path::Path, sync::Mutex};
+
+use oxc::diagnostics::Severity;
+use oxc_linter::Message;
+use rspack_core::ModuleIdentifier;
+use rspack_error::Diagnostic;
+use rustc_hash::FxHashSet;
+
+use crate::{
+  LineIndex, OX_LINT_PLUGIN_IDENTIFIER,
+  diagnostic::{message_code, message_rule_id, message_span},
+};
+
+/// oxlint 规则文档地址
+const RULE_DOCS_URL: &str = "https://oxc.rs/docs/guide/usage/linter/rules";
+
+/// code frame 中错误行前后展示的行数
+pub(crate) const CODE_FRAME_CONTEXT_LINES: usize = 2;
+
+/// 获取规则的文档链接，语法错误、未使用的指令等没有规则文档的消息返回 None
+pub fn rule_docs_url(message: &Message) -> Option<String> {
+  let code = &message.error.code;
+  let (scope, number) = (code.scope.as_ref()?, code.number.as_ref()?);
+
+  // 诊断中的 scope 是原始插件名（如 `eslint-plugin-react`），文档目录使用 oxlint 的插件名
+  let plugin = match scope.as_ref() {
+    "eslint" => "eslint",
+    "typescript-eslint" => "typescript",
+    "eslint-plugin-unicorn" => "unicorn",
+    "eslint-plugin-react" => "react",
+    "eslint-plugin-react-perf" => "react_perf",
+    "eslint-plugin-import" => "import",
+    "eslint-plugin-jsx-a11y" => "jsx_a11y",
+    "eslint-plugin-jest" => "jest",
+    "eslint-plugin-vitest" => "vitest",
+    "eslint-plugin-promise" => "promise",
+    "eslint-plugin-n" => "node",
+    "eslint-plugin-next" => "nextjs",
+    "eslint-plugin-jsdoc" => "jsdoc",
+    "oxc" => "oxc",
+    _ => return None,
+  };
+
+  Some(format!("{}/{}/{}.html", RULE_DOCS_URL, plugin, number))
+}
+
+/// 生成不带 ANSI 颜色的 code frame（浏览器 overlay 无法渲染终端颜色）
+///
+/// ```text
+///   3 | const a = 1;
+/// > 4 | debugger;
+///     | ^^^^^^^^^
+///   5 | export default a;
+/// ```
+pub fn code_frame(source_code: &str, line_index: &LineIndex, start: usize, end: usize) -> String {
+  let lines = source_code.lines().collect::<Vec<_>>();
+
+  let (line, column) = line_index.line_column(start);
+  let (end_line, end_column) = line_index.line_column(end);
+
+  let first = line.saturating_sub(CODE_FRAME_CONTEXT_LINES).max(1);
+  let last = (line + CODE_FRAME_CONTEXT_LINES).min(lines.len());
+  let gutter_width = last.to_string().len();
+
+  let mut frame = String::new();
+
+  for number in first..=last {
+    let text = lines[number - 1];
+
+    if number != line {
+      let _ = writeln!(
+        frame,
+        "  {:>width$} | {}",
+        number,
+        text,
+        width = gutter_width
+      );
+      continue;
+    }
+
+    let _ = writeln!(
+      frame,
+      "> {:>width$} | {}",
+      number,
+      text,
+      width = gutter_width
+    );
+
+    // 跨行的消息只标记到第一行行尾
+    let line_len = text.chars().count();
+    let underline_end = if end_line == line {
+      end_column.min(line_len + 1)
+    } else {
+      line_len + 1
+    };
+    let underline_len = underline_end.saturating_sub(column).max(1);
+
+    let _ = writeln!(
+      frame,
+      "  {:>width$} | {}{}",
+      "",
+      " ".repeat(column - 1),
+      "^".repeat(underline_len),
+      width = gutter_width
+    );
+  }
+
+  frame
+}
+
+/// 生成 overlay 展示的消息文本，`detailed` 为 false 时只包含位置和消息一行
+fn overlay_text(
+  display_path: &str,
+  source_code: &str,
+  line_index: &LineIndex,
+  message: &Message,
+  detailed: bool,
+) -> String {
+  let (start, end) = message_span(message);
+  let (line, column) = line_index.line_column(start);
+
+  let mut text = format!("{}:{}:{}\n", display_path, line, column);
+
+  match message_code(message) {
+    Some(code) => {
+      let _ = writeln!(text, "{}: {}", code, message.error.message);
+    }
+    None => {
+      let _ = writeln!(text, "{}", message.error.message);
+    }
+  }
+
+  if !detailed {
+    return text;
+  }
+
+  text.push('\n');
+  text.push_str(&code_frame(source_code, line_index, start, end));
+
+  if let Some(help) = &message.error.help {
+    let _ = write!(text, "\nHelp: {}", help);
+  }
+
+  if let Some(url) = rule_docs_url(message) {
+    let _ = write!(text, "\nDocs: {}", url);
+  }
+
+  text
+}
+
+/// 把 lint 消息转换为适合 dev server overlay 展示的诊断
+///
+/// - 消息为纯文本：文件位置、规则、不带 ANSI 颜色的 code frame、帮助信息和规则文档链接
+/// - 每条消息都会转换为诊断，stats、命令行输出中的数量和内容保持完整；去重只作用于 overlay 展示的详细内容：
+///   同一轮编译中重复的消息，以及热更新时上一轮已经报告过且仍然存在的警告，只保留位置和消息一行，
+///   不再附带 code frame 等详细内容；错误每轮都完整报告，在修复之前持续阻塞热更新
+#[derive(Debug, Default)]
+pub struct OverlayReporter {
+  /// 上一轮编译中报告过的消息指纹
+  reported: Mutex<FxHashSet<String>>,
+}
+
+impl OverlayReporter {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// 转换一个文件的 lint 消息，`seen` 收集本轮所有消息的指纹，编译结束时传给 `finish`
+  #[allow(clippy::too_many_arguments)]
+  pub fn to_diagnostics(
+    &self,
+    context: &Path,
+    path: &str,
+    source_code: &str,
+    messages: &[&Message],
+    module_identifier: Option<ModuleIdentifier>,
+    as_error: bool,
+    seen: &mut FxHashSet<String>,
+  ) -> Vec<Diagnostic> {
+    let reported = self.reported.lock().unwrap_or_else(|e| e.into_inner());
+    let line_index = LineIndex::new(source_code);
+
+    let display_path = Path::new(path)
+      .strip_prefix(context)
+      .map_or_else(|_| path.to_string(), |p| p.to_string_lossy().into_owned());
+
+    let mut diagnostics = vec![];
+
+    for message in messages {
+      let is_error = as_error && message.error.severity == Severity::Error;
+
+      let (start, _) = message_span(message);
+      let (line, _) = line_index.line_column(start);
+
+      // 用所在行的内容代替偏移量，修改文件其它位置导致行号变化时指纹保持不变
+      let line_text = source_code.lines().nth(line - 1).unwrap_or_default();
+      let fingerprint = format!(
+        "{}\0{}\0{}\0{}",
+        path,
+        message_rule_id(message).unwrap_or_default(),
+        message.error.message,
+        line_text.trim()
+      );
+
+      let is_new =
+        seen.insert(fingerprint.clone()) && (is_error || !reported.contains(&fingerprint));
+
+      let text = overlay_text(&display_path, source_code, &line_index, message, is_new);
+
+      let diagnostic = if is_error {
+        Diagnostic::error(OX_LINT_PLUGIN_IDENTIFIER.to_string(), text)
+      } else {
+        Diagnostic::warn(OX_LINT_PLUGIN_IDENTIFIER.to_string(), text)
+      };
+
+      diagnostics.push(
+        diagnostic
+          .with_file(Some(path.into()))
+          .with_module_identifier(module_identifier),
+      );
+    }
+
+    diagnostics
+  }
+
+  /// 编译结束时记录本轮的消息指纹，已修复的消息再次出现时会重新报告
+  pub fn finish(&self, seen: FxHashSet<String>) {
+    *self.reported.lock().unwrap_or_else(|e| e.into_inner()) = seen;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use oxc::{diagnostics::OxcDiagnostic, span::Span};
+  use oxc_linter::PossibleFixes;
+
+  use super::*;
+
+  #[test]
+  fn test_code_frame() {
+    let source = "const a = 1;\ndebugger;\nexport default a;\n";
+    let index = LineIndex::new(source);
+
+    assert_eq!(
+      code_frame(source, &index, 13, 22),
+      "  1 | const a = 1;\n> 2 | debugger;\n    | ^^^^^^^^^\n  3 | export default a;\n"
+    );
+
+    // 没有位置信息的消息定位到文件开头
+    assert_eq!(
+      code_frame(source, &index, 0, 0),
+      "> 1 | const a = 1;\n    | ^\n  2 | debugger;\n  3 | export default a;\n"
+    );
+  }
+
+  fn debugger_message(severity: Severity) -> Message {
+    let error = OxcDiagnostic::warn("`debugger` statement is not allowed")
+      .with_error_code("eslint", "no-debugger")
+      .with_severity(severity)
+      .with_label(Span::new(13, 22));
+
+    Message::new(error, PossibleFixes::None)
+  }
+
+  #[test]
+  fn test_overlay_text() {
+    let source = "const a = 1;\ndebugger;\nexport default a;\n";
+    let index = LineIndex::new(source);
+    let message = debugger_message(Severity::Warning);
+
+    assert_eq!(
+      overlay_text("src/index.js", source, &index, &message, false),
+      "src/index.js:2:1\neslint(no-debugger): `debugger` statement is not allowed\n"
+    );
+    assert_eq!(
+      overlay_text("src/index.js", source, &index, &message, true),
+      "src/index.js:2:1\neslint(no-debugger): `debugger` statement is not allowed\n\n  1 | const a = 1;\n> 2 | debugger;\n    | ^^^^^^^^^\n  3 | export default a;\n\nDocs: https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html"
+    );
+  }
+
+  #[test]
+  fn test_to_diagnostics_keeps_every_message() {
+    let source = "const a = 1;\ndebugger;\nexport default a;\n";
+    let warning = debugger_message(Severity::Warning);
+    let reporter = OverlayReporter::new();
+
+    // 重复的消息和上一轮已报告过的警告仍然转换为诊断，只是不再附带 code frame
+    for _ in 0..2 {
+      let mut seen = FxHashSet::default();
+      let diagnostics = reporter.to_diagnostics(
+        Path::new("/project"),
+        "/project/src/index.js",
+        source,
+        &[&warning, &warning],
+        None,
+        false,
+        &mut seen,
+      );
+
+      assert_eq!(diagnostics.len(), 2);
+      assert_eq!(seen.len(), 1);
+
+      reporter.finish(seen);
+    }
+  }
+}
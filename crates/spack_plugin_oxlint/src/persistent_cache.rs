@@ -0,0 +1,342 @@
+use std::{
+  collections::HashMap,
+  hash::Hasher,
+  path::{Path, PathBuf},
+  sync::{
+    RwLock,
+    atomic::{AtomicBool, Ordering},
+  },
+};
+
+use dashmap::DashMap;
+use oxc::{
+  diagnostics::{LabeledSpan, OxcDiagnostic, Severity},
+  span::Span,
+};
+use oxc_linter::{Fix, Message, PossibleFixes};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use serde::{Deserialize, Serialize};
+
+use crate::ResolvedConfig;
+
+/// 缓存格式版本，缓存结构变化时需要修改
+const CACHE_VERSION: &str = "3";
+
+/// 当前使用的 oxlint 版本，需要与 Cargo.toml 中 oxc 依赖的 tag 保持一致
+const OXLINT_VERSION: &str = "1.29.0";
+
+/// 缓存文件名（位于 `output_dir` 下）
+pub const PERSISTENT_CACHE_FILE_NAME: &str = ".oxlint-cache.json";
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheFile {
+  version: String,
+  config_hash: String,
+  files: HashMap<String, CachedFile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedFile {
+  content_hash: String,
+  messages: Vec<CachedMessage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedLabel {
+  label: Option<String>,
+  offset: usize,
+  len: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedFix {
+  content: String,
+  message: Option<String>,
+  start: u32,
+  end: u32,
+}
+
+impl CachedFix {
+  fn from_fix(fix: &Fix) -> Self {
+    Self {
+      content: fix.content.to_string(),
+      message: fix.message.as_ref().map(|m| m.to_string()),
+      start: fix.span.start,
+      end: fix.span.end,
+    }
+  }
+
+  fn to_fix(&self) -> Fix {
+    let fix = Fix::new(self.content.clone(), Span::new(self.start, self.end));
+
+    match &self.message {
+      Some(message) => fix.with_message(message.clone()),
+      None => fix,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedMessage {
+  scope: Option<String>,
+  number: Option<String>,
+  severity: String,
+  message: String,
+  help: Option<String>,
+  labels: Vec<CachedLabel>,
+  /// 可用的修复（报告和 `on_linted` 中的 `fixable` 依赖它）
+  fixes: Vec<CachedFix>,
+}
+
+impl CachedMessage {
+  fn from_message(message: &Message) -> Self {
+    let error = &message.error;
+
+    let severity = match error.severity {
+      Severity::Error => "error",
+      Severity::Warning => "warning",
+      Severity::Advice => "advice",
+    };
+
+    let labels = error
+      .labels
+      .as_ref()
+      .map(|labels| {
+        labels
+          .iter()
+          .map(|label| CachedLabel {
+            label: label.label().map(|l| l.to_string()),
+            offset: label.offset(),
+            len: label.len(),
+          })
+          .collect()
+      })
+      .unwrap_or_default();
+
+    Self {
+      scope: error.code.scope.as_ref().map(|s| s.to_string()),
+      number: error.code.number.as_ref().map(|n| n.to_string()),
+      severity: severity.to_string(),
+      message: error.message.to_string(),
+      help: error.help.as_ref().map(|h| h.to_string()),
+      labels,
+      fixes: match &message.fixes {
+        PossibleFixes::None => vec![],
+        PossibleFixes::Single(fix) => vec![CachedFix::from_fix(fix)],
+        PossibleFixes::Multiple(fixes) => fixes.iter().map(CachedFix::from_fix).collect(),
+      },
+    }
+  }
+
+  /// 还原为 oxlint 的 `Message`
+  fn to_message(&self) -> Message {
+    let mut diagnostic = match self.severity.as_str() {
+      "error" => OxcDiagnostic::error(self.message.clone()),
+      "warning" => OxcDiagnostic::warn(self.message.clone()),
+      _ => OxcDiagnostic::warn(self.message.clone()).with_severity(Severity::Advice),
+    };
+
+    if let Some(help) = &self.help {
+      diagnostic = diagnostic.with_help(help.clone());
+    }
+
+    if let (Some(scope), Some(number)) = (&self.scope, &self.number) {
+      diagnostic = diagnostic.with_error_code(scope.clone(), number.clone());
+    }
+
+    diagnostic = diagnostic.with_labels(
+      self
+        .labels
+        .iter()
+        .map(|l| LabeledSpan::new(l.label.clone(), l.offset, l.len)),
+    );
+
+    let fixes = match self.fixes.as_slice() {
+      [] => PossibleFixes::None,
+      [fix] => PossibleFixes::Single(fix.to_fix()),
+      fixes => PossibleFixes::Multiple(fixes.iter().map(CachedFix::to_fix).collect()),
+    };
+
+    Message::new(diagnostic, fixes)
+  }
+}
+
+/// 持久化（磁盘）lint 缓存
+///
+/// - 以文件内容 hash 为 key，命中时直接复用上一次的 lint 结果，避免冷启动时重复 lint 未修改的文件
+/// - 缓存整体绑定到 `config_hash`（有效配置 + 影响 lint 结果的插件选项 + oxlint 版本 + 缓存格式版本），任何一项变化都会使整个缓存失效
+/// - 只在 `save()` 时写盘；watch 模式下 watcher 报告删除的文件时移除对应的结果
+#[derive(Debug)]
+pub struct PersistentCache {
+  path: PathBuf,
+  config_hash: RwLock<String>,
+  /// 影响 lint 结果但不在 Oxlintrc 中的插件选项（未使用指令的报告级别、框架提示等）
+  lint_options: String,
+  files: DashMap<String, CachedFile>,
+  dirty: AtomicBool,
+}
+
+impl PersistentCache {
+  /// 从磁盘加载缓存，文件不存在、无法解析或配置不一致时返回空缓存
+  pub fn load(output_dir: impl AsRef<Path>, config: &ResolvedConfig, lint_options: String) -> Self {
+    let path = output_dir.as_ref().join(PERSISTENT_CACHE_FILE_NAME);
+    let config_hash = config_hash(config, &lint_options);
+
+    let files = std::fs::read_to_string(&path)
+      .ok()
+      .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+      .filter(|cache| cache.version == CACHE_VERSION && cache.config_hash == config_hash)
+      .map(|cache| cache.files.into_iter().collect())
+      .unwrap_or_default();
+
+    Self {
+      path,
+      config_hash: RwLock::new(config_hash),
+      lint_options,
+      files,
+      dirty: AtomicBool::new(false),
+    }
+  }
+
+  /// 查询缓存，文件内容未变化时返回上一次的 lint 结果
+  ///
+  /// `need_fixes` 为 true 时（开启了自动修复），含可修复消息的结果不会命中，
+  /// 因为命中缓存时不会执行修复
+  pub fn get(&self, file: &str, content_hash: &str, need_fixes: bool) -> Option<Vec<Message>> {
+    let cached = self.files.get(file)?;
+
+    if cached.content_hash != content_hash {
+      return None;
+    }
+
+    if need_fixes && cached.messages.iter().any(|m| !m.fixes.is_empty()) {
+      return None;
+    }
+
+    Some(
+      cached
+        .messages
+        .iter()
+        .map(CachedMessage::to_message)
+        .collect(),
+    )
+  }
+
+  /// 写入缓存
+  pub fn insert(&self, file: String, content_hash: String, messages: &[Message]) {
+    let cached = CachedFile {
+      content_hash,
+      messages: messages.iter().map(CachedMessage::from_message).collect(),
+    };
+
+    self.files.insert(file, cached);
+    self.dirty.store(true, Ordering::Relaxed);
+  }
+
+  /// 移除已删除文件的结果
+  pub fn remove(&self, file: &str) {
+    if self.files.remove(file).is_some() {
+      self.dirty.store(true, Ordering::Relaxed);
+    }
+  }
+
+  /// 配置变化（watch 模式下重新加载配置）后清空缓存
+  pub fn reset(&self, config: &ResolvedConfig) {
+    *self.config_hash.write().unwrap_or_else(|e| e.into_inner()) =
+      config_hash(config, &self.lint_options);
+    self.files.clear();
+    self.dirty.store(true, Ordering::Relaxed);
+  }
+
+  /// 把缓存写回磁盘（无变化时跳过）
+  pub fn save(&self) -> Result<(), String> {
+    if !self.dirty.swap(false, Ordering::Relaxed) {
+      return Ok(());
+    }
+
+    let cache = CacheFile {
+      version: CACHE_VERSION.to_string(),
+      config_hash: self
+        .config_hash
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone(),
+      files: self
+        .files
+        .iter()
+        .map(|e| (e.key().clone(), e.value().clone()))
+        .collect(),
+    };
+
+    let content = serde_json::to_string(&cache)
+      .map_err(|e| format!("Failed to serialize oxlint cache: {}", e))?;
+
+    if let Some(parent) = self.path.parent() {
+      std::fs::create_dir_all(parent)
+        .map_err(|e| format!("Failed to create cache directory {:?}: {}", parent, e))?;
+    }
+
+    std::fs::write(&self.path, content)
+      .map_err(|e| format!("Failed to write oxlint cache to {:?}: {}", self.path, e))
+  }
+}
+
+fn hash_str(value: &str) -> String {
+  let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+  hasher.write(value.as_bytes());
+  hasher.digest(&HashDigest::Hex).encoded().to_string()
+}
+
+/// 计算文件内容 hash
+pub fn content_hash(source_code: &str) -> String {
+  hash_str(source_code)
+}
+
+/// 计算有效配置（包括嵌套配置）的 hash（包含影响 lint 结果的插件选项、oxlint 版本和缓存格式版本）
+fn config_hash(config: &ResolvedConfig, lint_options: &str) -> String {
+  let nested = config
+    .nested
+    .iter()
+    .map(|nested| (&nested.dir, &nested.oxlintrc))
+    .collect::<Vec<_>>();
+  let config = serde_json::to_string(&(&config.oxlintrc, nested)).unwrap_or_default();
+  hash_str(&format!(
+    "{}:{}:{}:{}",
+    CACHE_VERSION, OXLINT_VERSION, lint_options, config
+  ))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_oxlint_version_matches_dependency() {
+    let manifest = include_str!("../../../Cargo.toml");
+
+    // OXLINT_VERSION 需要与 oxc 依赖的 tag 保持一致，否则升级 oxlint 后旧的缓存结果不会失效
+    assert!(
+      manifest.contains(&format!("tag = \"oxlint_v{}\"", OXLINT_VERSION)),
+      "OXLINT_VERSION {} does not match the oxc tag in Cargo.toml",
+      OXLINT_VERSION
+    );
+  }
+
+  #[test]
+  fn test_cached_message_keeps_fixes() {
+    let message = Message::new(
+      OxcDiagnostic::warn("Unexpected var").with_error_code("eslint", "no-var"),
+      PossibleFixes::Single(Fix::new("let", Span::new(0, 3))),
+    );
+
+    let restored = CachedMessage::from_message(&message).to_message();
+
+    assert!(!restored.fixes.is_empty());
+    assert_eq!(restored.error.message, message.error.message);
+  }
+}
@@ -1,11 +1,30 @@
-use std::{path::Path, sync::Arc};
-
-use ignore::WalkBuilder;
-use rspack_core::{AsyncModulesArtifact, Compilation, CompilationParams, Plugin};
+use std::{
+  path::{Path, PathBuf},
+  sync::{
+    Arc, OnceLock, RwLock,
+    atomic::{AtomicBool, Ordering},
+  },
+  time::Instant,
+};
+
+use rspack_core::{
+  AsyncModulesArtifact, Compilation, CompilationParams, DependencyCategory, Plugin,
+  ResolveOptionsWithDependencyType,
+};
 use rspack_error::{Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
-
-use crate::{OxlintPluginOpts, lint_cache::LintCache, lint_runner::LintRunner};
+use rspack_paths::ArcPath;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+  Baseline, FileMatcher, FileReport, LineIndex, LintMessage, LintResult, LintStats,
+  OverlayReporter, OxlintPluginOpts, OxlintPluginResp, PersistentCache, SourceExcerpt,
+  diagnostic::to_rspack_diagnostic,
+  framework_flags,
+  lint_cache::{LintCache, LintEntry},
+  lint_runner::LintRunner,
+  report::{unreachable_files, write_report, write_unreachable_report},
+};
 
 pub const OX_LINT_PLUGIN_IDENTIFIER: &'static str = "Spack.OxlintPlugin";
 
@@ -14,57 +33,335 @@ pub const OX_LINT_PLUGIN_IDENTIFIER: &'static str = "Spack.OxlintPlugin";
 pub struct OxlintPlugin {
   #[allow(unused)]
   options: OxlintPluginOpts,
-  lint_runner: Arc<LintRunner>,
+  /// 首次编译时根据 context 解析配置并构建
+  lint_runner: OnceLock<LintRunner>,
   lint_cache: Arc<LintCache>,
+  /// 配置中的 `ignorePatterns`，用于构建文件匹配器
+  ignore_patterns: OnceLock<Vec<String>>,
+  /// 文件匹配器，首次编译时根据 context 构建
+  file_matcher: OnceLock<FileMatcher>,
+  /// 首次全量 lint 时 lint 的文件（只 lint 变更文件时不包括未变更的文件），用于找出不在模块图中的文件
+  walked_files: OnceLock<Vec<String>>,
+  /// 用户配置文件及其 `extends` 链，watch 模式下变化时重新加载配置
+  config_files: RwLock<Vec<PathBuf>>,
+  /// reachable_only 模式下上一轮模块图中的文件，配置变化时需要重新 lint
+  reachable_files: RwLock<Vec<String>>,
+  /// 需要 lint 内联脚本的 HTML/EJS 模板匹配器，未配置 `templates` 时为空
+  template_matcher: OnceLock<FileMatcher>,
+  /// 首次编译时找到的模板文件
+  template_files: OnceLock<Vec<PathBuf>>,
+  /// lint 统计，未开启 `stats` 时为空
+  stats: Option<LintStats>,
+  /// dev server overlay 诊断的转换器，未开启 `overlay` 时为空
+  overlay: Option<OverlayReporter>,
+  /// 是否已经更新过 baseline，watch 模式下只在首次编译时更新
+  baseline_updated: AtomicBool,
 }
 
 impl OxlintPlugin {
   pub fn new(options: OxlintPluginOpts) -> Self {
-    // 1. 构建配置
-    let oxlintrc = options
-      .build_oxlintrc()
-      .expect("Failed to build oxlint config");
-
     let lint_cache = Arc::new(LintCache::new());
 
-    let lint_runner = Arc::new(LintRunner::new(oxlintrc, options.show_warning));
-
-    Self::new_inner(options, lint_runner, lint_cache)
+    let stats = options.stats.then(LintStats::new);
+
+    let overlay = options.overlay.then(OverlayReporter::new);
+
+    Self::new_inner(
+      options,
+      OnceLock::new(),
+      lint_cache,
+      OnceLock::new(),
+      OnceLock::new(),
+      OnceLock::new(),
+      RwLock::new(vec![]),
+      RwLock::new(vec![]),
+      OnceLock::new(),
+      OnceLock::new(),
+      stats,
+      overlay,
+      AtomicBool::new(false),
+    )
   }
 }
 
 impl OxlintPlugin {
-  fn build_overrides(
-    dir: impl AsRef<Path>,
-  ) -> std::result::Result<ignore::overrides::Override, ignore::Error> {
-    let mut overrides = ignore::overrides::OverrideBuilder::new(&dir);
-
-    // 包含特定扩展名（分别添加）
-    overrides.add("*.js")?;
-    overrides.add("*.jsx")?;
-    overrides.add("*.ts")?;
-    overrides.add("*.tsx")?;
-    overrides.add("*.mjs")?;
-    overrides.add("*.cjs")?;
-    overrides.add("*.cts")?;
-    overrides.add("*.mts")?;
-
-    // 排除特定文件
-    overrides.add("!*.d.ts")?;
-    overrides.add("!*.min.js")?;
-
-    // 排除目录
-    overrides.add("!node_modules/**")?;
-    overrides.add("!**/.lego/**")?;
-    overrides.add("!**/node_modules/**")?;
-    overrides.add("!dist/**")?;
-    overrides.add("!build/**")?;
-    overrides.add("!coverage/**")?;
-    overrides.add("!.git/**")?;
-
-    let overrides = overrides.build()?;
-
-    Ok(overrides)
+  /// 解析配置并构建 linter（只在首次编译时执行一次）
+  ///
+  /// 框架检测、嵌套配置等以 `context`（rspack 的项目根目录）为根目录，而不是进程的工作目录
+  fn init_lint_runner(&self, context: &Path) -> Result<&LintRunner> {
+    if let Some(lint_runner) = self.lint_runner.get() {
+      return Ok(lint_runner);
+    }
+
+    let config = self
+      .options
+      .resolve_config(context)
+      .map_err(|e| rspack_error::Error::error(format!("Failed to build oxlint config: {}", e)))?;
+
+    let framework_hints = framework_flags(&self.options.resolve_frameworks(context));
+
+    // 影响 lint 结果但不在配置文件中的选项，变化时持久化缓存失效
+    let lint_options = format!("{:?}:{:?}", self.options.unused_directives, framework_hints);
+
+    let persistent_cache = self
+      .options
+      .cache
+      .then(|| PersistentCache::load(&self.options.output_dir, &config, lint_options));
+
+    let lint_runner = LintRunner::new(
+      &config,
+      self.options.fix,
+      self.options.unused_directives,
+      framework_hints,
+      persistent_cache,
+    )?;
+
+    let _ = self
+      .ignore_patterns
+      .set(config.oxlintrc.ignore_patterns.clone());
+    *self.config_files.write().unwrap_or_else(|e| e.into_inner()) = config.files;
+
+    Ok(self.lint_runner.get_or_init(|| lint_runner))
+  }
+
+  /// 首次编译时构建的 linter，之后的钩子都在 `this_compilation` 之后执行
+  fn lint_runner(&self) -> &LintRunner {
+    self
+      .lint_runner
+      .get()
+      .expect("oxlint runner should be initialized in this_compilation")
+  }
+
+  /// 配置中的 `ignorePatterns`
+  fn ignore_patterns(&self) -> &[String] {
+    self
+      .ignore_patterns
+      .get()
+      .map(Vec::as_slice)
+      .unwrap_or_default()
+  }
+
+  /// 构建文件匹配器（只在首次编译时构建一次）
+  fn init_file_matcher(&self, context: &Path) -> Result<&FileMatcher> {
+    if let Some(file_matcher) = self.file_matcher.get() {
+      return Ok(file_matcher);
+    }
+
+    let file_matcher = FileMatcher::new(
+      context,
+      &self.options.include,
+      &self.options.exclude,
+      self.ignore_patterns(),
+    )
+    .map_err(|e| rspack_error::Error::error(format!("Failed to build file matcher: {}", e)))?;
+
+    Ok(self.file_matcher.get_or_init(|| file_matcher))
+  }
+
+  /// 构建模板匹配器并找出所有需要 lint 内联脚本的模板（只在首次编译时执行一次）
+  fn init_template_files(&self, context: &Path) -> Result<()> {
+    if self.options.templates.is_empty() || self.template_files.get().is_some() {
+      return Ok(());
+    }
+
+    let template_matcher = FileMatcher::new(
+      context,
+      &self.options.templates,
+      &[],
+      self.ignore_patterns(),
+    )
+    .map_err(|e| rspack_error::Error::error(format!("Failed to build template matcher: {}", e)))?;
+
+    let _ = self.template_files.set(template_matcher.walk());
+    let _ = self.template_matcher.set(template_matcher);
+
+    Ok(())
+  }
+
+  /// 是否为需要 lint 内联脚本的模板
+  fn is_template(&self, path: impl AsRef<Path>) -> bool {
+    self
+      .template_matcher
+      .get()
+      .is_some_and(|matcher| matcher.is_match(path))
+  }
+
+  fn config_files(&self) -> Vec<PathBuf> {
+    self
+      .config_files
+      .read()
+      .unwrap_or_else(|e| e.into_inner())
+      .clone()
+  }
+
+  /// 本轮编译是否由配置文件（或其 `extends` 链）的变化触发
+  fn is_config_modified(&self, compilation: &Compilation) -> bool {
+    let config_files = self.config_files();
+
+    compilation
+      .modified_files
+      .iter()
+      .chain(compilation.removed_files.iter())
+      .any(|file| config_files.iter().any(|f| f.as_path() == file.as_ref()))
+  }
+
+  /// 移除被删除或重命名的文件的 lint 结果（同步更新错误和警告计数）
+  fn evict_removed_files(&self, compilation: &Compilation) {
+    for file in compilation.removed_files.iter() {
+      let path: &Path = file.as_ref();
+
+      self.lint_cache.remove_from_cache(&path.to_string_lossy());
+      self.lint_runner().remove_file(path);
+
+      if let Some(stats) = &self.stats {
+        stats.remove(&path.to_string_lossy());
+      }
+    }
+
+    // watcher 没有报告的删除/重命名，按文件是否存在兜底
+    self.lint_cache.remove_missing_files();
+  }
+
+  /// 重新 lint 被修改但不在模块图中的文件
+  ///
+  /// 这些文件不会触发 succeed_module，如果不处理，cache 中会一直保留修改前的结果；
+  /// 模块图中的文件在这里 lint 后会被标记为已 lint，succeed_module 中会跳过
+  async fn lint_modified_files(&self, compilation: &Compilation) -> Result<()> {
+    let Some(file_matcher) = self.file_matcher.get() else {
+      return Ok(());
+    };
+
+    // reachable_only 模式下只关心模块图中的文件（以及不在模块图中的模板）
+    let files = compilation
+      .modified_files
+      .iter()
+      .map(|file| AsRef::<Path>::as_ref(file).to_path_buf())
+      .filter(|file| {
+        file.is_file()
+          && (self.is_template(file)
+            || (!self.options.reachable_only && file_matcher.is_match(file)))
+      })
+      .filter(|file| {
+        self
+          .lint_cache
+          .try_mark_as_linted(file.to_string_lossy().into_owned())
+      })
+      .collect::<Vec<_>>();
+
+    self.lint_files(files).await
+  }
+
+  /// 重新加载配置并重新 lint 上一轮 lint 过的所有文件
+  ///
+  /// 配置无效时输出警告并继续使用原来的配置；`ignorePatterns` 的变化需要重启才能生效
+  async fn reload_config(&self, compilation: &mut Compilation) -> Result<()> {
+    let context = compilation.options.context.as_std_path();
+
+    let reloaded = self.options.resolve_config(context).and_then(|config| {
+      self.lint_runner().reload(&config)?;
+      Ok(config.files)
+    });
+
+    let config_files = match reloaded {
+      Ok(config_files) => config_files,
+      Err(e) => {
+        compilation.push_diagnostic(Diagnostic::warn(
+          OX_LINT_PLUGIN_IDENTIFIER.to_string(),
+          format!(
+            "Failed to reload oxlint config, keep using the previous config: {}",
+            e
+          ),
+        ));
+        return Ok(());
+      }
+    };
+
+    *self.config_files.write().unwrap_or_else(|e| e.into_inner()) = config_files;
+
+    let mut files = if self.options.reachable_only {
+      self
+        .reachable_files
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+    } else {
+      self.walked_files.get().cloned().unwrap_or_default()
+    };
+
+    // 热更新中新增的文件只存在于 cache 中
+    files.extend(self.lint_cache.entries().into_iter().map(|(path, _)| path));
+    files.extend(
+      self
+        .template_files
+        .get()
+        .into_iter()
+        .flatten()
+        .map(|f| f.to_string_lossy().into_owned()),
+    );
+    files.sort();
+    files.dedup();
+
+    let Some(file_matcher) = self.file_matcher.get() else {
+      return Ok(());
+    };
+
+    let files = files
+      .into_iter()
+      .filter(|file| {
+        Path::new(file).exists() && (file_matcher.is_match(file) || self.is_template(file))
+      })
+      .collect::<Vec<_>>();
+
+    // 避免 succeed_module 重复 lint
+    self.lint_cache.mark_files_as_linted(&files);
+
+    self
+      .lint_files(files.into_iter().map(PathBuf::from).collect())
+      .await
+  }
+
+  /// lint 单个文件，开启统计时记录耗时
+  async fn lint_file(&self, path: &Path) -> Result<LintResult> {
+    let start = Instant::now();
+
+    let mut result = self.lint_runner().lint(path).await?;
+
+    if let Some(stats) = &self.stats {
+      stats.record(&path.to_string_lossy(), start.elapsed(), result.cached);
+    }
+
+    self
+      .lint_cache
+      .add_warnings(std::mem::take(&mut result.warnings));
+
+    Ok(result)
+  }
+
+  /// lint 一批文件并更新 cache
+  async fn lint_files(&self, files: Vec<PathBuf>) -> Result<()> {
+    for file in files {
+      let resource = file.to_string_lossy().into_owned();
+
+      let result = self.lint_file(&file).await?;
+
+      if result.fixed {
+        self.lint_cache.mark_as_fixed(resource.clone());
+      }
+
+      if !result.messages.is_empty() {
+        self.lint_cache.insert_cache(
+          resource,
+          LintEntry {
+            source: SourceExcerpt::new(&result.source_code, &result.messages),
+            messages: result.messages,
+          },
+        );
+      } else {
+        self.lint_cache.remove_from_cache(&resource);
+      }
+    }
+
+    Ok(())
   }
 }
 
@@ -108,45 +405,120 @@ pub(crate) async fn this_compilation(
   // 这样后续热更新时，succeed_module 中的文件可以正常 lint
   self.lint_cache.clear_linted_files();
 
+  let context = compilation.options.context.as_path();
+
+  // 首次启动时解析配置并构建 linter
+  let lint_runner = self.init_lint_runner(context.as_std_path())?;
+
+  // import 插件的跨模块规则使用 rspack 的 resolver（包含 alias 等配置）解析依赖
+  lint_runner.set_resolver(
+    compilation
+      .resolver_factory
+      .get(ResolveOptionsWithDependencyType {
+        resolve_options: None,
+        resolve_to_context: false,
+        dependency_category: DependencyCategory::Esm,
+      }),
+  );
+
+  // 首次启动时构建文件匹配器，并找出需要 lint 内联脚本的模板
+  if is_initialized {
+    self.init_file_matcher(context.as_std_path())?;
+    self.init_template_files(context.as_std_path())?;
+  }
+
+  // watch 模式下监听配置文件（及其 extends 链）和模板文件的变化
+  let watched_files = self
+    .config_files()
+    .into_iter()
+    .chain(self.template_files.get().cloned().unwrap_or_default());
+
+  compilation
+    .file_dependencies
+    .extend(watched_files.map(|file| ArcPath::from(file.as_path())));
+
   // 只在首次启动时执行全量 lint
-  // 热更新时跳过（succeed_module 会处理变更的文件）
+  // 热更新时跳过（succeed_module 会处理变更的文件），配置文件变化时重新 lint 所有文件
   if !is_initialized {
+    // 修复写回源文件会再次触发编译，watch 模式下重新编译时只报告不修复
+    lint_runner.disable_fix();
+
+    self.evict_removed_files(compilation);
+
+    if self.is_config_modified(compilation) {
+      self.reload_config(compilation).await?;
+    } else {
+      self.lint_modified_files(compilation).await?;
+    }
+
     return Ok(());
   }
 
   // 首次启动：执行全量 lint
-  let context = compilation.options.context.as_path();
+  // 模板不在模块图中，不受 reachable_only 影响
+  let template_files = self.template_files.get().cloned().unwrap_or_default();
 
-  let overrides = Self::build_overrides(context).expect("Failed to build ignore overrides.");
+  self.lint_cache.mark_files_as_linted(
+    &template_files
+      .iter()
+      .map(|f| f.to_string_lossy().into_owned())
+      .collect::<Vec<_>>(),
+  );
 
-  let files = WalkBuilder::new(context)
-    .overrides(overrides)
-    .build()
-    .filter_map(|e| e.ok())
-    .filter(|e| e.file_type().map_or(false, |ft| ft.is_file()))
-    .map(|e| e.path().to_owned())
-    .collect::<Vec<_>>();
+  self.lint_files(template_files).await?;
 
-  // 收集所有文件路径用于批量标记
-  let file_paths: Vec<String> = files
-    .iter()
-    .map(|f| f.to_string_lossy().into_owned())
-    .collect();
+  let Some(file_matcher) = self.file_matcher.get() else {
+    return Ok(());
+  };
+
+  // 只 lint 模块图中的模块：不遍历目录，全部交给 succeed_module 处理
+  if self.options.reachable_only {
+    return Ok(());
+  }
+
+  let files = file_matcher.walk();
+
+  // 只 lint 相对 git base 变更的文件（或调用方提供的文件列表），未变更的文件本轮跳过
+  let (files, skipped_files): (Vec<_>, Vec<_>) = match &self.options.changed_files {
+    Some(changed_files) => match changed_files.resolve(context.as_std_path()) {
+      Ok(changed) => files
+        .into_iter()
+        .partition(|f| f.canonicalize().map_or(false, |p| changed.contains(&p))),
+      Err(e) => {
+        // 无法获取变更文件时退回全量 lint
+        compilation.push_diagnostic(Diagnostic::warn(
+          OX_LINT_PLUGIN_IDENTIFIER.to_string(),
+          format!(
+            "Failed to resolve changed files, fallback to full lint: {}",
+            e
+          ),
+        ));
+        (files, vec![])
+      }
+    },
+    None => (files, vec![]),
+  };
+
+  let to_paths = |files: &[PathBuf]| {
+    files
+      .iter()
+      .map(|f| f.to_string_lossy().into_owned())
+      .collect::<Vec<_>>()
+  };
+
+  let file_paths = to_paths(&files);
 
   // 记录所有 lint 过的文件（首次启动时，避免 succeed_module 重复 lint）
   self.lint_cache.mark_files_as_linted(&file_paths);
 
-  for file in files {
-    let resource = file.to_string_lossy().into_owned();
+  // 未变更的文件在首次编译中不会被 succeed_module 再 lint，但不算作 lint 过的文件
+  self
+    .lint_cache
+    .mark_files_as_skipped(&to_paths(&skipped_files));
 
-    let messages = self.lint_runner.lint(&file).await?;
+  let _ = self.walked_files.set(file_paths);
 
-    if !messages.is_empty() {
-      self.lint_cache.insert_cache(resource, messages);
-    }
-  }
-
-  Ok(())
+  self.lint_files(files).await
 }
 
 #[plugin_hook(rspack_core::CompilationFinishModules for OxlintPlugin,stage=rspack_core::Compilation::PROCESS_ASSETS_STAGE_REPORT)]
@@ -155,27 +527,244 @@ pub(crate) async fn finish_modules(
   compilation: &mut Compilation,
   _async_modules_artifact: &mut AsyncModulesArtifact,
 ) -> Result<()> {
-  // 在所有 succeed_module 完成后，读取最终的错误计数
-  // 此时计数器已经包含了本轮编译的所有 lint 结果
-  let error_count = self.lint_cache.get_error_count();
-  let warning_count = self.lint_cache.get_warning_count();
+  // 报告本轮被自动修复的文件
+  let fixed_files = self.lint_cache.take_fixed_files();
 
-  let diagnostics = compilation.diagnostics_mut();
+  if !fixed_files.is_empty() {
+    compilation.push_diagnostic(Diagnostic::warn(
+      OX_LINT_PLUGIN_IDENTIFIER.to_string(),
+      format!(
+        "Oxlint fixed {} file(s):\n  {}",
+        fixed_files.len(),
+        fixed_files.join("\n  ")
+      ),
+    ));
+  }
 
-  if warning_count > 0 {
-    diagnostics.push(Diagnostic::warn(
+  // 报告 lint 过程中的警告（如处理 disable 指令失败后的降级）
+  for warning in self.lint_cache.take_warnings() {
+    compilation.push_diagnostic(Diagnostic::warn(
       OX_LINT_PLUGIN_IDENTIFIER.to_string(),
-      format!("Lint warnings in total: {}", warning_count),
+      warning,
     ));
   }
 
-  if error_count > 0 && self.options.fail_on_error {
-    diagnostics.push(Diagnostic::error(
+  // 建立文件路径 -> module identifier 的映射，用于把诊断挂到所属模块上
+  // Vue 等单文件组件会派生出带 query 的子模块（如 `App.vue?vue&type=script`），只取不带 query 的模块
+  let (module_paths, module_identifiers) = {
+    let module_graph = compilation.get_module_graph();
+
+    let mut module_paths = FxHashSet::default();
+    let mut module_identifiers = FxHashMap::default();
+
+    for (identifier, module) in module_graph.modules() {
+      let Some(normal_module) = module.as_normal_module() else {
+        continue;
+      };
+
+      let resource_data = normal_module.resource_resolved_data();
+      let Some(path) = resource_data.path() else {
+        continue;
+      };
+
+      if resource_data.resource() == path.as_str() {
+        module_identifiers.insert(path.to_string(), identifier);
+      }
+
+      module_paths.insert(path.to_string());
+    }
+
+    (module_paths, module_identifiers)
+  };
+
+  // 记录本轮模块图中的文件，配置变化时重新 lint
+  if self.options.reachable_only {
+    *self
+      .reachable_files
+      .write()
+      .unwrap_or_else(|e| e.into_inner()) = module_paths.iter().cloned().collect();
+  }
+
+  // 在所有 succeed_module 完成后，cache 中已经包含了本轮编译的所有 lint 结果
+  // 把每一条 lint 消息转换为 rspack 的诊断（错误/警告），由 rspack 统一输出到 stats、overlay 等
+  // reachable_only 模式下，不再被引用的模块（如热更新中删除了 import）的结果不再输出
+  let entries = self
+    .lint_cache
+    .entries()
+    .into_iter()
+    .filter(|(path, _)| {
+      !self.options.reachable_only || module_paths.contains(path) || self.is_template(path)
+    })
+    .collect::<Vec<_>>();
+
+  let context = compilation.options.context.as_path();
+
+  // 只报告 baseline 之外新增的违规
+  let mut baseline = self
+    .options
+    .baseline
+    .as_ref()
+    .map(|file_name| Baseline::new(context.as_std_path(), file_name.as_str()));
+
+  if let Some(baseline) = &mut baseline
+    && self.options.update_baseline
+    && !self.baseline_updated.swap(true, Ordering::Relaxed)
+  {
+    baseline
+      .update(&self.lint_cache.linted_files(), &entries)
+      .map_err(rspack_error::Error::error)?;
+  }
+
+  let mut diagnostics = vec![];
+  let mut error_count = 0;
+  let mut warning_count = 0;
+  let mut linted_files = vec![];
+  // 本轮 overlay 诊断的消息指纹
+  let mut overlay_seen = FxHashSet::default();
+
+  for (path, entry) in &entries {
+    let module_identifier = module_identifiers.get(path).copied();
+
+    let messages = match &mut baseline {
+      Some(baseline) => baseline.filter(path, &entry.messages),
+      None => entry.messages.iter().collect(),
+    };
+
+    if messages.is_empty() {
+      continue;
+    }
+
+    // 每个文件只还原一次源码，由该文件的所有消息共享
+    let source_code = entry.source.to_source();
+
+    // 只在配置了 on_linted 时才计算行列号
+    if self.options.on_linted.is_some() {
+      let line_index = LineIndex::new(&source_code);
+      linted_files.push(FileReport {
+        file_path: path.clone(),
+        messages: messages
+          .iter()
+          .map(|message| LintMessage::from_message(message, &line_index))
+          .collect(),
+      });
+    }
+
+    let mut reported = vec![];
+
+    for message in messages {
+      let is_error = message.error.severity == oxc::diagnostics::Severity::Error;
+
+      if is_error {
+        error_count += 1;
+      } else {
+        warning_count += 1;
+      }
+
+      if !is_error && !self.options.show_warning {
+        continue;
+      }
+
+      reported.push(message);
+    }
+
+    match &self.overlay {
+      Some(overlay) => diagnostics.extend(overlay.to_diagnostics(
+        context.as_std_path(),
+        path,
+        &source_code,
+        &reported,
+        module_identifier,
+        self.options.fail_on_error,
+        &mut overlay_seen,
+      )),
+      None => diagnostics.extend(reported.into_iter().map(|message| {
+        to_rspack_diagnostic(
+          path,
+          &source_code,
+          message,
+          module_identifier,
+          self.options.fail_on_error,
+        )
+      })),
+    }
+  }
+
+  if let Some(overlay) = &self.overlay {
+    overlay.finish(overlay_seen);
+  }
+
+  compilation.diagnostics_mut().extend(diagnostics);
+
+  // 警告数超过阈值时构建失败（与 eslint 的 --max-warnings 一致）
+  if let Some(max_warnings) = self.options.max_warnings
+    && warning_count > max_warnings
+  {
+    compilation.push_diagnostic(Diagnostic::error(
       OX_LINT_PLUGIN_IDENTIFIER.to_string(),
-      format!("Lint errors in total: {}", error_count),
+      format!(
+        "Too many lint warnings ({}). Maximum allowed is {}.",
+        warning_count, max_warnings
+      ),
     ));
   }
 
+  // 输出 lint 统计汇总
+  let stats = self.stats.as_ref().map(|stats| stats.summary(&entries));
+
+  if let Some(stats) = &stats {
+    eprintln!(
+      "{}",
+      stats.render_table(compilation.options.context.as_path().as_std_path())
+    );
+  }
+
+  if let Some(on_linted) = &self.options.on_linted {
+    let response = OxlintPluginResp::new(linted_files, error_count, warning_count, stats);
+
+    if let Err(e) = on_linted(response).await {
+      compilation.push_diagnostic(Diagnostic::warn(
+        OX_LINT_PLUGIN_IDENTIFIER.to_string(),
+        format!("onLinted callback failed: {:?}", e),
+      ));
+    }
+  }
+
+  // 持久化 lint 缓存，写入失败不影响构建
+  if let Err(e) = self.lint_runner().save_cache() {
+    compilation.push_diagnostic(Diagnostic::warn(OX_LINT_PLUGIN_IDENTIFIER.to_string(), e));
+  }
+
+  // 输出机器可读的 lint 报告（供 CI 作为产物使用）
+  if !self.options.reports.is_empty() {
+    let files = entries
+      .iter()
+      .map(|(path, entry)| FileReport {
+        file_path: path.clone(),
+        messages: LintMessage::from_messages(&entry.messages, &entry.source.to_source()),
+      })
+      .collect::<Vec<_>>();
+
+    let context = compilation.options.context.as_path();
+
+    for report in &self.options.reports {
+      write_report(report, &files, context.as_std_path()).map_err(rspack_error::Error::error)?;
+    }
+  }
+
+  // 输出已 lint 但不在模块图中的文件（可能是死代码，或应该通过 exclude 排除的文件）
+  if let (Some(output_path), Some(walked_files), Some(file_matcher)) = (
+    &self.options.unreachable_report,
+    self.walked_files.get(),
+    self.file_matcher.get(),
+  ) {
+    let unreachable_files = unreachable_files(walked_files, &module_paths, file_matcher);
+
+    let context = compilation.options.context.as_path();
+
+    write_unreachable_report(output_path, &unreachable_files, context.as_std_path())
+      .map_err(rspack_error::Error::error)?;
+  }
+
   // 生产环境下，如果有错误且配置了 fail_on_error，则终止构建
   // if error_count > 0 && !compilation.options.mode.is_development() && self.options.fail_on_error {
   //   return Err(rspack_error::Error::error(format!(
@@ -198,13 +787,18 @@ pub(crate) async fn succeed_module(
     return Ok(());
   };
 
-  let resource = normal_module.resource_resolved_data().resource();
-
-  let overrides = Self::build_overrides("/").expect("Failed to build ignore overrides.");
+  // 使用不带 query 的文件路径，Vue 等单文件组件的子模块（`App.vue?vue&type=script`）指向同一个文件，
+  // 通过 try_mark_as_linted 保证只 lint 一次
+  let Some(resource) = normal_module.resource_resolved_data().path() else {
+    return Ok(());
+  };
+  let resource = resource.as_str();
 
-  let matcher = overrides.matched(resource, false);
+  let Some(file_matcher) = self.file_matcher.get() else {
+    return Ok(());
+  };
 
-  if !matcher.is_whitelist() {
+  if !file_matcher.is_match(resource) {
     return Ok(());
   }
 
@@ -214,11 +808,21 @@ pub(crate) async fn succeed_module(
   // 使用 try_mark_as_linted 避免竞态条件（检查和标记是原子操作）
   if self.lint_cache.try_mark_as_linted(resource.to_string()) {
     // 首次标记，执行 lint
-    let messages = self.lint_runner.lint(resource).await?;
+    let result = self.lint_file(Path::new(resource)).await?;
+
+    if result.fixed {
+      self.lint_cache.mark_as_fixed(resource.to_string());
+    }
 
     // 更新 cache（自动更新错误计数器）
-    if !messages.is_empty() {
-      self.lint_cache.insert_cache(resource.to_string(), messages);
+    if !result.messages.is_empty() {
+      self.lint_cache.insert_cache(
+        resource.to_string(),
+        LintEntry {
+          source: SourceExcerpt::new(&result.source_code, &result.messages),
+          messages: result.messages,
+        },
+      );
     } else {
       // 如果没有错误，从 cache 中移除（如果之前有的话）
       self.lint_cache.remove_from_cache(resource);
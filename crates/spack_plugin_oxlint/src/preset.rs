@@ -0,0 +1,104 @@
+use serde_json::{Value, json};
+use strum_macros::EnumString;
+
+/// 内置规则预设
+///
+/// - `recommended`: 内置规则集（默认）
+/// - `strict`: 在内置规则集基础上开启 correctness / suspicious / perf 分类
+/// - `react`: 在 jsx/tsx 文件上额外开启 React 及 hooks 相关规则
+/// - `typescript`: 在 ts/tsx 文件上额外开启 TypeScript 相关规则
+#[derive(EnumString, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[strum(serialize_all = "camelCase")]
+pub enum Preset {
+  #[default]
+  Recommended,
+  Strict,
+  React,
+  Typescript,
+}
+
+/// 内置配置中 TypeScript 文件 override 的 `files`
+pub(crate) const TYPESCRIPT_FILES: &str = "*.{ts,tsx,cts,mts}";
+
+/// 内置配置中 JSX 文件 override 的 `files`
+pub(crate) const JSX_FILES: &str = "*.{jsx,tsx}";
+
+impl Preset {
+  /// 把预设合并到内置配置上
+  pub fn apply(&self, config: &mut Value) {
+    match self {
+      Preset::Recommended => {}
+      Preset::Strict => merge_object(
+        &mut config["categories"],
+        json!({
+          "correctness": "error",
+          "suspicious": "warn",
+          "perf": "warn"
+        }),
+      ),
+      Preset::React => merge_object(
+        override_rules(config, JSX_FILES),
+        json!({
+          "react/jsx-key":[2],
+          "react/jsx-no-duplicate-props":[2],
+          "react/jsx-no-undef":[2],
+          "react/no-children-prop":[2],
+          "react/no-danger-with-children":[2],
+          "react/no-direct-mutation-state":[2],
+          "react/no-string-refs":[2],
+          "react/rules-of-hooks":[2],
+          "react/exhaustive-deps":[1]
+        }),
+      ),
+      Preset::Typescript => merge_object(
+        override_rules(config, TYPESCRIPT_FILES),
+        json!({
+          "typescript/no-duplicate-enum-values":[2],
+          "typescript/no-explicit-any":[1],
+          "typescript/no-extra-non-null-assertion":[2],
+          "typescript/no-misused-new":[2],
+          "typescript/no-non-null-asserted-optional-chain":[2],
+          "typescript/no-unsafe-declaration-merging":[2],
+          "typescript/prefer-as-const":[2],
+          "typescript/triple-slash-reference":[2],
+          "typescript/consistent-type-imports":[1]
+        }),
+      ),
+    }
+  }
+}
+
+/// 按 `files` 查找 override 的规则，不存在时追加一个新的 override
+fn override_rules<'a>(config: &'a mut Value, files: &str) -> &'a mut Value {
+  if !config["overrides"].is_array() {
+    config["overrides"] = json!([]);
+  }
+
+  let Value::Array(overrides) = &mut config["overrides"] else {
+    unreachable!();
+  };
+
+  let index = match overrides
+    .iter()
+    .position(|item| item["files"] == json!([files]))
+  {
+    Some(index) => index,
+    None => {
+      overrides.push(json!({ "files": [files], "rules": {} }));
+      overrides.len() - 1
+    }
+  };
+
+  let rules = &mut overrides[index]["rules"];
+  if !rules.is_object() {
+    *rules = json!({});
+  }
+
+  rules
+}
+
+fn merge_object(target: &mut Value, source: Value) {
+  if let (Some(target), Value::Object(source)) = (target.as_object_mut(), source) {
+    target.extend(source);
+  }
+}
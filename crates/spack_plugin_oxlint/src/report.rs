@@ -0,0 +1,492 @@
+use std::path::Path;
+
+use rustc_hash::FxHashSet;
+use serde_json::{Value, json};
+use strum_macros::EnumString;
+
+use crate::{
+  FileMatcher,
+  lint_message::{LintMessage, LintSeverity},
+};
+
+/// lint 报告格式
+///
+/// - `json`: 与 eslint `--format json` 兼容
+/// - `sarif`: SARIF 2.1.0，用于 GitHub code scanning 等
+/// - `junit`: JUnit XML，用于 CI 的测试结果面板
+/// - `checkstyle`: checkstyle XML
+#[derive(EnumString, Debug, Clone, Copy, PartialEq, Eq)]
+#[strum(serialize_all = "camelCase")]
+pub enum ReportFormat {
+  Json,
+  Sarif,
+  Junit,
+  Checkstyle,
+}
+
+/// lint 报告输出配置
+#[derive(Debug, Clone)]
+pub struct LintReport {
+  pub format: ReportFormat,
+  /// 报告文件路径，相对路径基于 compilation 的 context
+  pub output_path: String,
+}
+
+/// 单个文件的 lint 结果（报告的输入）
+#[derive(Debug, Clone)]
+pub struct FileReport {
+  pub file_path: String,
+  pub messages: Vec<LintMessage>,
+}
+
+impl FileReport {
+  fn count(&self, severity: LintSeverity) -> usize {
+    self
+      .messages
+      .iter()
+      .filter(|m| m.severity == severity)
+      .count()
+  }
+
+  fn count_fixable(&self, severity: LintSeverity) -> usize {
+    self
+      .messages
+      .iter()
+      .filter(|m| m.severity == severity && m.fixable)
+      .count()
+  }
+}
+
+impl ReportFormat {
+  /// 生成报告内容
+  ///
+  /// `context` 用于在 SARIF 中输出相对路径
+  pub fn render(&self, files: &[FileReport], context: &Path) -> String {
+    match self {
+      ReportFormat::Json => render_json(files),
+      ReportFormat::Sarif => render_sarif(files, context),
+      ReportFormat::Junit => render_junit(files),
+      ReportFormat::Checkstyle => render_checkstyle(files),
+    }
+  }
+}
+
+/// 把报告写入文件（自动创建父目录）
+pub fn write_report(
+  report: &LintReport,
+  files: &[FileReport],
+  context: &Path,
+) -> Result<(), String> {
+  let output_path = context.join(&report.output_path);
+
+  if let Some(parent) = output_path.parent() {
+    std::fs::create_dir_all(parent)
+      .map_err(|e| format!("Failed to create report directory {:?}: {}", parent, e))?;
+  }
+
+  std::fs::write(&output_path, report.format.render(files, context))
+    .map_err(|e| format!("Failed to write lint report to {:?}: {}", output_path, e))
+}
+
+/// 找出已 lint 但不在模块图中的文件
+///
+/// 只保留仍然匹配 include/exclude 的文件，不会列出未 lint 或被排除的文件
+pub fn unreachable_files(
+  linted_files: &[String],
+  module_paths: &FxHashSet<String>,
+  file_matcher: &FileMatcher,
+) -> Vec<String> {
+  linted_files
+    .iter()
+    .filter(|file| !module_paths.contains(*file) && file_matcher.is_match(file))
+    .cloned()
+    .collect()
+}
+
+/// 输出已 lint 但不在模块图中的文件列表（JSON）
+pub fn write_unreachable_report(
+  output_path: &str,
+  files: &[String],
+  context: &Path,
+) -> Result<(), String> {
+  let output_path = context.join(output_path);
+
+  if let Some(parent) = output_path.parent() {
+    std::fs::create_dir_all(parent)
+      .map_err(|e| format!("Failed to create report directory {:?}: {}", parent, e))?;
+  }
+
+  let report = json!({
+    "count": files.len(),
+    "files": files,
+  });
+
+  std::fs::write(
+    &output_path,
+    serde_json::to_string_pretty(&report).unwrap_or_default(),
+  )
+  .map_err(|e| {
+    format!(
+      "Failed to write unreachable files report to {:?}: {}",
+      output_path, e
+    )
+  })
+}
+
+fn eslint_severity(severity: LintSeverity) -> u8 {
+  match severity {
+    LintSeverity::Error => 2,
+    LintSeverity::Warning => 1,
+  }
+}
+
+fn render_json(files: &[FileReport]) -> String {
+  let results = files
+    .iter()
+    .map(|file| {
+      let messages = file
+        .messages
+        .iter()
+        .map(|m| {
+          json!({
+            "ruleId": m.rule_id,
+            "severity": eslint_severity(m.severity),
+            "message": m.message,
+            "line": m.line,
+            "column": m.column,
+            "endLine": m.end_line,
+            "endColumn": m.end_column,
+          })
+        })
+        .collect::<Vec<_>>();
+
+      json!({
+        "filePath": file.file_path,
+        "messages": messages,
+        "suppressedMessages": [],
+        "errorCount": file.count(LintSeverity::Error),
+        "fatalErrorCount": 0,
+        "warningCount": file.count(LintSeverity::Warning),
+        "fixableErrorCount": file.count_fixable(LintSeverity::Error),
+        "fixableWarningCount": file.count_fixable(LintSeverity::Warning),
+        "usedDeprecatedRules": [],
+      })
+    })
+    .collect::<Vec<_>>();
+
+  serde_json::to_string_pretty(&Value::Array(results)).unwrap_or_default()
+}
+
+fn render_sarif(files: &[FileReport], context: &Path) -> String {
+  let mut rule_ids = files
+    .iter()
+    .flat_map(|file| file.messages.iter().filter_map(|m| m.rule_id.clone()))
+    .collect::<Vec<_>>();
+  rule_ids.sort();
+  rule_ids.dedup();
+
+  let rules = rule_ids
+    .iter()
+    .map(|id| json!({ "id": id }))
+    .collect::<Vec<_>>();
+
+  let results = files
+    .iter()
+    .flat_map(|file| {
+      let uri = Path::new(&file.file_path)
+        .strip_prefix(context)
+        .map(|p| p.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| file.file_path.clone());
+
+      file.messages.iter().map(move |m| {
+        let level = match m.severity {
+          LintSeverity::Error => "error",
+          LintSeverity::Warning => "warning",
+        };
+
+        json!({
+          "ruleId": m.rule_id,
+          "level": level,
+          "message": { "text": m.message },
+          "locations": [{
+            "physicalLocation": {
+              "artifactLocation": { "uri": uri },
+              "region": {
+                "startLine": m.line,
+                "startColumn": m.column,
+                "endLine": m.end_line,
+                "endColumn": m.end_column,
+              }
+            }
+          }]
+        })
+      })
+    })
+    .collect::<Vec<_>>();
+
+  let sarif = json!({
+    "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+    "version": "2.1.0",
+    "runs": [{
+      "tool": {
+        "driver": {
+          "name": "oxlint",
+          "informationUri": "https://oxc.rs",
+          "rules": rules,
+        }
+      },
+      "results": results,
+    }]
+  });
+
+  serde_json::to_string_pretty(&sarif).unwrap_or_default()
+}
+
+fn render_junit(files: &[FileReport]) -> String {
+  let mut output = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<testsuites>\n");
+
+  for file in files {
+    let name = escape_xml(&file.file_path);
+
+    output.push_str(&format!(
+      "  <testsuite package=\"org.eslint\" time=\"0\" tests=\"{}\" errors=\"{}\" name=\"{}\">\n",
+      file.messages.len(),
+      file.count(LintSeverity::Error),
+      name
+    ));
+
+    for m in &file.messages {
+      let rule_id = m.rule_id.as_deref().unwrap_or("unknown");
+
+      let level = match m.severity {
+        LintSeverity::Error => "Error",
+        LintSeverity::Warning => "Warning",
+      };
+
+      let detail = format!(
+        "line {}, col {}, {} - {} ({})",
+        m.line, m.column, level, m.message, rule_id
+      );
+
+      output.push_str(&format!(
+        "    <testcase time=\"0\" name=\"org.eslint.{}\" classname=\"{}\">\n",
+        escape_xml(rule_id),
+        name
+      ));
+      output.push_str(&format!(
+        "      <failure message=\"{}\">{}</failure>\n",
+        escape_xml(&m.message),
+        escape_xml(&detail)
+      ));
+      output.push_str("    </testcase>\n");
+    }
+
+    output.push_str("  </testsuite>\n");
+  }
+
+  output.push_str("</testsuites>\n");
+  output
+}
+
+fn render_checkstyle(files: &[FileReport]) -> String {
+  let mut output =
+    String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<checkstyle version=\"4.3\">\n");
+
+  for file in files {
+    output.push_str(&format!(
+      "  <file name=\"{}\">\n",
+      escape_xml(&file.file_path)
+    ));
+
+    for m in &file.messages {
+      let severity = match m.severity {
+        LintSeverity::Error => "error",
+        LintSeverity::Warning => "warning",
+      };
+
+      output.push_str(&format!(
+        "    <error line=\"{}\" column=\"{}\" severity=\"{}\" message=\"{}\" source=\"{}\" />\n",
+        m.line,
+        m.column,
+        severity,
+        escape_xml(&m.message),
+        escape_xml(m.rule_id.as_deref().unwrap_or_default())
+      ));
+    }
+
+    output.push_str("  </file>\n");
+  }
+
+  output.push_str("</checkstyle>\n");
+  output
+}
+
+/// XML 转义（属性值和文本节点通用）
+fn escape_xml(value: &str) -> String {
+  let mut escaped = String::with_capacity(value.len());
+
+  for c in value.chars() {
+    match c {
+      '&' => escaped.push_str("&amp;"),
+      '<' => escaped.push_str("&lt;"),
+      '>' => escaped.push_str("&gt;"),
+      '"' => escaped.push_str("&quot;"),
+      '\'' => escaped.push_str("&apos;"),
+      _ => escaped.push(c),
+    }
+  }
+
+  escaped
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn message(rule_id: Option<&str>, severity: LintSeverity, fixable: bool) -> LintMessage {
+    LintMessage {
+      rule_id: rule_id.map(String::from),
+      severity,
+      message: "Unexpected \"debugger\" & <script>".to_string(),
+      help: None,
+      start: 13,
+      end: 22,
+      line: 2,
+      column: 1,
+      end_line: 2,
+      end_column: 10,
+      fixable,
+    }
+  }
+
+  fn files() -> Vec<FileReport> {
+    vec![FileReport {
+      file_path: "/project/src/index.js".to_string(),
+      messages: vec![
+        message(Some("eslint/no-debugger"), LintSeverity::Error, true),
+        message(None, LintSeverity::Warning, false),
+      ],
+    }]
+  }
+
+  fn render(format: ReportFormat) -> String {
+    format.render(&files(), Path::new("/project"))
+  }
+
+  #[test]
+  fn test_unreachable_files() {
+    let root = std::env::temp_dir().join("test_oxlint_unreachable_files");
+    let file_matcher = FileMatcher::new(&root, &[], &["src/legacy/**".to_string()], &[]).unwrap();
+
+    let file = |name: &str| root.join(name).to_string_lossy().into_owned();
+
+    let linted_files = vec![
+      file("src/index.js"),
+      file("src/unused.ts"),
+      file("src/legacy/old.js"),
+      file("src/types.d.ts"),
+    ];
+    let module_paths = FxHashSet::from_iter([file("src/index.js")]);
+
+    assert_eq!(
+      unreachable_files(&linted_files, &module_paths, &file_matcher),
+      vec![file("src/unused.ts")]
+    );
+  }
+
+  #[test]
+  fn test_parse_format() {
+    assert_eq!("json".parse::<ReportFormat>(), Ok(ReportFormat::Json));
+    assert_eq!(
+      "checkstyle".parse::<ReportFormat>(),
+      Ok(ReportFormat::Checkstyle)
+    );
+    assert!("html".parse::<ReportFormat>().is_err());
+  }
+
+  #[test]
+  fn test_render_json() {
+    let report = serde_json::from_str::<Value>(&render(ReportFormat::Json)).unwrap();
+    let file = &report[0];
+
+    assert_eq!(file["filePath"], json!("/project/src/index.js"));
+    assert_eq!(file["errorCount"], json!(1));
+    assert_eq!(file["warningCount"], json!(1));
+    assert_eq!(file["fixableErrorCount"], json!(1));
+    assert_eq!(file["fixableWarningCount"], json!(0));
+    assert_eq!(
+      file["messages"][0],
+      json!({
+        "ruleId": "eslint/no-debugger",
+        "severity": 2,
+        "message": "Unexpected \"debugger\" & <script>",
+        "line": 2,
+        "column": 1,
+        "endLine": 2,
+        "endColumn": 10,
+      })
+    );
+    assert_eq!(file["messages"][1]["severity"], json!(1));
+  }
+
+  #[test]
+  fn test_render_sarif() {
+    let report = serde_json::from_str::<Value>(&render(ReportFormat::Sarif)).unwrap();
+    let run = &report["runs"][0];
+
+    assert_eq!(report["version"], json!("2.1.0"));
+    assert_eq!(
+      run["tool"]["driver"]["rules"],
+      json!([{ "id": "eslint/no-debugger" }])
+    );
+
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0]["level"], json!("error"));
+    assert_eq!(results[1]["level"], json!("warning"));
+
+    // 文件路径相对 context
+    assert_eq!(
+      results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+      json!("src/index.js")
+    );
+    assert_eq!(
+      results[0]["locations"][0]["physicalLocation"]["region"]["endColumn"],
+      json!(10)
+    );
+  }
+
+  #[test]
+  fn test_render_junit() {
+    assert_eq!(
+      render(ReportFormat::Junit),
+      r#"<?xml version="1.0" encoding="utf-8"?>
+<testsuites>
+  <testsuite package="org.eslint" time="0" tests="2" errors="1" name="/project/src/index.js">
+    <testcase time="0" name="org.eslint.eslint/no-debugger" classname="/project/src/index.js">
+      <failure message="Unexpected &quot;debugger&quot; &amp; &lt;script&gt;">line 2, col 1, Error - Unexpected &quot;debugger&quot; &amp; &lt;script&gt; (eslint/no-debugger)</failure>
+    </testcase>
+    <testcase time="0" name="org.eslint.unknown" classname="/project/src/index.js">
+      <failure message="Unexpected &quot;debugger&quot; &amp; &lt;script&gt;">line 2, col 1, Warning - Unexpected &quot;debugger&quot; &amp; &lt;script&gt; (unknown)</failure>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+    );
+  }
+
+  #[test]
+  fn test_render_checkstyle() {
+    assert_eq!(
+      render(ReportFormat::Checkstyle),
+      r#"<?xml version="1.0" encoding="utf-8"?>
+<checkstyle version="4.3">
+  <file name="/project/src/index.js">
+    <error line="2" column="1" severity="error" message="Unexpected &quot;debugger&quot; &amp; &lt;script&gt;" source="eslint/no-debugger" />
+    <error line="2" column="1" severity="warning" message="Unexpected &quot;debugger&quot; &amp; &lt;script&gt;" source="" />
+  </file>
+</checkstyle>
+"#
+    );
+  }
+}
@@ -0,0 +1,30 @@
+use crate::{FileReport, LintStatsSummary};
+
+/// `on_linted` 回调的参数，每轮编译 `finish_modules` 后回调一次
+///
+/// 只包含本轮实际报告的消息（已过滤 baseline 中的违规，不受 `show_warning` 影响），
+/// 没有消息的文件不会出现在 `files` 中
+#[derive(Debug, Clone)]
+pub struct OxlintPluginResp {
+  pub files: Vec<FileReport>,
+  pub error_count: usize,
+  pub warning_count: usize,
+  /// lint 统计汇总（开启 `stats` 时）
+  pub stats: Option<LintStatsSummary>,
+}
+
+impl OxlintPluginResp {
+  pub fn new(
+    files: Vec<FileReport>,
+    error_count: usize,
+    warning_count: usize,
+    stats: Option<LintStatsSummary>,
+  ) -> Self {
+    Self {
+      files,
+      error_count,
+      warning_count,
+      stats,
+    }
+  }
+}
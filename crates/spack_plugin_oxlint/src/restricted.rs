@@ -1,5 +1,8 @@
+use std::path::Path;
+
 use rspack_cacheable::cacheable;
 use serde::Serialize;
+use serde_json::{Value, json};
 
 #[cacheable]
 #[derive(Debug, Clone, Serialize)]
@@ -7,3 +10,150 @@ pub struct Restricted {
   pub name: String,
   pub message: String,
 }
+
+/// `no-restricted-imports` 中按模块名限制的一项（对应 `paths`）
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestrictedImport {
+  /// 模块名，如 `lodash`、`fs`
+  pub name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub message: Option<String>,
+  /// 只限制这些导入名，为空时限制整个模块
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub import_names: Vec<String>,
+  /// 只允许这些导入名，其余导入名都被限制（与 `import_names` 互斥）
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub allow_import_names: Vec<String>,
+  /// 是否允许 `import type`
+  #[serde(skip_serializing_if = "std::ops::Not::not")]
+  pub allow_type_imports: bool,
+}
+
+/// `no-restricted-imports` 中按模式限制的一项（对应 `patterns`）
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestrictedImportPattern {
+  /// gitignore 风格的 glob，如 `["lodash/*", "!lodash/pick"]`
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub group: Vec<String>,
+  /// 正则表达式（与 `group` 二选一）
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub regex: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub message: Option<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub import_names: Vec<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub allow_import_names: Vec<String>,
+  /// 按正则限制导入名
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub import_name_pattern: Option<String>,
+  /// 按正则允许导入名
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub allow_import_name_pattern: Option<String>,
+  /// 匹配时是否区分大小写（默认不区分）
+  #[serde(skip_serializing_if = "std::ops::Not::not")]
+  pub case_sensitive: bool,
+  #[serde(skip_serializing_if = "std::ops::Not::not")]
+  pub allow_type_imports: bool,
+}
+
+/// 只对部分文件生效的导入限制，用于约束包/目录之间的分层（如 `src/client/**` 不能导入 `fs`）
+///
+/// 生成为配置中的 `overrides`，这些文件上的限制为全局限制加上这里的限制
+#[derive(Debug, Clone, Default)]
+pub struct RestrictedImportScope {
+  /// 生效的文件 glob，相对于项目根目录（rspack 的 `context`）
+  pub files: Vec<String>,
+  pub paths: Vec<RestrictedImport>,
+  pub patterns: Vec<RestrictedImportPattern>,
+}
+
+/// 生成 `no-restricted-imports` 规则的配置
+pub(crate) fn restricted_imports_rule(
+  paths: &[RestrictedImport],
+  patterns: &[RestrictedImportPattern],
+) -> Value {
+  json!([1, {
+    "paths": paths,
+    "patterns": patterns
+  }])
+}
+
+impl RestrictedImportScope {
+  /// 生成对应的 override，`paths`/`patterns` 为全局限制
+  ///
+  /// 生成的配置没有对应的配置文件，override 的 glob 会匹配文件的绝对路径，所以这里把 glob 转为基于 `root` 的绝对路径
+  pub(crate) fn to_override(
+    &self,
+    root: &Path,
+    paths: &[RestrictedImport],
+    patterns: &[RestrictedImportPattern],
+  ) -> Value {
+    let files = self
+      .files
+      .iter()
+      .map(|glob| {
+        let glob = glob.trim_start_matches("./");
+        if Path::new(glob).is_absolute() {
+          glob.to_string()
+        } else {
+          format!("{}/{}", root.to_string_lossy().trim_end_matches('/'), glob)
+        }
+      })
+      .collect::<Vec<_>>();
+
+    let paths = paths.iter().chain(&self.paths).cloned().collect::<Vec<_>>();
+    let patterns = patterns
+      .iter()
+      .chain(&self.patterns)
+      .cloned()
+      .collect::<Vec<_>>();
+
+    json!({
+      "files": files,
+      "rules": {
+        "no-restricted-imports": restricted_imports_rule(&paths, &patterns)
+      }
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_scope_override() {
+    let global = vec![RestrictedImport {
+      name: "lodash".to_string(),
+      message: Some("use lodash-es".to_string()),
+      ..Default::default()
+    }];
+
+    let scope = RestrictedImportScope {
+      files: vec!["./src/client/**".to_string()],
+      paths: vec![RestrictedImport {
+        name: "fs".to_string(),
+        allow_type_imports: true,
+        ..Default::default()
+      }],
+      patterns: vec![],
+    };
+
+    let value = scope.to_override(Path::new("/repo/"), &global, &[]);
+
+    assert_eq!(value["files"], json!(["/repo/src/client/**"]));
+    assert_eq!(
+      value["rules"]["no-restricted-imports"],
+      json!([1, {
+        "paths": [
+          { "name": "lodash", "message": "use lodash-es" },
+          { "name": "fs", "allowTypeImports": true }
+        ],
+        "patterns": []
+      }])
+    );
+  }
+}
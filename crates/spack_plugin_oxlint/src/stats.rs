@@ -0,0 +1,174 @@
+use std::{
+  collections::HashMap,
+  path::Path,
+  sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+  time::Duration,
+};
+
+use dashmap::DashMap;
+use serde::Serialize;
+
+use crate::{LintEntry, diagnostic::message_rule_id};
+
+/// 汇总中输出的最慢文件数
+const SLOWEST_FILES_LIMIT: usize = 10;
+
+/// 汇总表格中输出的规则数
+const TOP_RULES_LIMIT: usize = 10;
+
+/// lint 统计（跨首次全量 lint 和后续增量 lint 累计）
+///
+/// - 每个文件记录最近一次 lint 的耗时
+/// - 总耗时、lint 次数、持久化缓存命中次数为累计值
+///
+/// oxlint 没有暴露单条规则的耗时，规则维度只统计命中次数
+#[derive(Debug, Default)]
+pub struct LintStats {
+  file_durations: DashMap<String, Duration>,
+  /// 累计耗时（纳秒）
+  total_nanos: AtomicU64,
+  lint_count: AtomicUsize,
+  cache_hits: AtomicUsize,
+}
+
+/// 单个文件的 lint 耗时
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileDuration {
+  pub file_path: String,
+  pub duration_ms: f64,
+}
+
+/// 单条规则的命中次数
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleHits {
+  pub rule_id: String,
+  pub count: usize,
+}
+
+/// lint 统计汇总
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintStatsSummary {
+  /// 所有 lint（首次 + 增量）的累计耗时
+  pub total_duration_ms: f64,
+  /// 累计 lint 次数（同一文件多次 lint 计多次）
+  pub lint_count: usize,
+  /// 命中持久化缓存的次数
+  pub cache_hits: usize,
+  /// 最近一次 lint 耗时最长的文件（降序）
+  pub slowest_files: Vec<FileDuration>,
+  /// 当前结果中每条规则的命中次数（降序），不包括规则的耗时
+  pub rules: Vec<RuleHits>,
+}
+
+impl LintStats {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// 记录一次 lint
+  pub fn record(&self, file: &str, duration: Duration, cached: bool) {
+    self.file_durations.insert(file.to_string(), duration);
+    self
+      .total_nanos
+      .fetch_add(duration.as_nanos() as u64, Ordering::Relaxed);
+    self.lint_count.fetch_add(1, Ordering::Relaxed);
+
+    if cached {
+      self.cache_hits.fetch_add(1, Ordering::Relaxed);
+    }
+  }
+
+  /// 文件被删除后不再统计其耗时
+  pub fn remove(&self, file: &str) {
+    self.file_durations.remove(file);
+  }
+
+  /// 根据当前的 lint 结果生成汇总
+  pub fn summary(&self, entries: &[(String, LintEntry)]) -> LintStatsSummary {
+    let mut slowest_files = self
+      .file_durations
+      .iter()
+      .map(|e| (e.key().clone(), *e.value()))
+      .collect::<Vec<_>>();
+
+    slowest_files.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    slowest_files.truncate(SLOWEST_FILES_LIMIT);
+
+    let mut rule_counts = HashMap::<String, usize>::new();
+
+    for (_, entry) in entries {
+      for rule_id in entry.messages.iter().filter_map(message_rule_id) {
+        *rule_counts.entry(rule_id).or_default() += 1;
+      }
+    }
+
+    let mut rules = rule_counts
+      .into_iter()
+      .map(|(rule_id, count)| RuleHits { rule_id, count })
+      .collect::<Vec<_>>();
+
+    rules.sort_by(|a, b| {
+      b.count
+        .cmp(&a.count)
+        .then_with(|| a.rule_id.cmp(&b.rule_id))
+    });
+
+    LintStatsSummary {
+      total_duration_ms: to_ms(Duration::from_nanos(
+        self.total_nanos.load(Ordering::Relaxed),
+      )),
+      lint_count: self.lint_count.load(Ordering::Relaxed),
+      cache_hits: self.cache_hits.load(Ordering::Relaxed),
+      slowest_files: slowest_files
+        .into_iter()
+        .map(|(file_path, duration)| FileDuration {
+          file_path,
+          duration_ms: to_ms(duration),
+        })
+        .collect(),
+      rules,
+    }
+  }
+}
+
+impl LintStatsSummary {
+  /// 渲染为终端输出的汇总表格，文件路径相对于 `context`
+  pub fn render_table(&self, context: &Path) -> String {
+    let mut lines = vec![format!(
+      "Oxlint stats: {} lint(s) in {:.1}ms, {} cache hit(s)",
+      self.lint_count, self.total_duration_ms, self.cache_hits
+    )];
+
+    if !self.rules.is_empty() {
+      lines.push(format!("  {:<48} {:>8}", "Rule", "Hits"));
+      for rule in self.rules.iter().take(TOP_RULES_LIMIT) {
+        lines.push(format!("  {:<48} {:>8}", rule.rule_id, rule.count));
+      }
+      lines.push(
+        "  (per-rule timing is not available, oxlint runs all rules in a single pass)".to_string(),
+      );
+    }
+
+    if !self.slowest_files.is_empty() {
+      lines.push(format!("  {:<48} {:>8}", "File", "Time"));
+      for file in &self.slowest_files {
+        let file_path = Path::new(&file.file_path);
+        let file_path = file_path.strip_prefix(context).unwrap_or(file_path);
+        lines.push(format!(
+          "  {:<48} {:>6.1}ms",
+          file_path.display(),
+          file.duration_ms
+        ));
+      }
+    }
+
+    lines.join("\n")
+  }
+}
+
+fn to_ms(duration: Duration) -> f64 {
+  duration.as_secs_f64() * 1000.0
+}
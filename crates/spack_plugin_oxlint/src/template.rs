@@ -0,0 +1,347 @@
+use oxc::{allocator::Allocator, span::SourceType};
+use oxc_linter::loader::JavaScriptSource;
+
+/// 支持提取内联脚本的模板文件扩展名
+pub const TEMPLATE_EXTENSIONS: &[&str] = &["html", "htm", "ejs"];
+
+/// EJS 标签的开始
+const TEMPLATE_TAG_OPEN: &str = "<%";
+/// EJS 标签的结束
+const TEMPLATE_TAG_CLOSE: &str = "%>";
+
+/// HTML 注释的开始
+const COMMENT_OPEN: &str = "<!--";
+/// HTML 注释的结束
+const COMMENT_CLOSE: &str = "-->";
+
+/// 提取 HTML/EJS 模板中的内联 `<script>` 代码块
+///
+/// - 跳过 HTML 注释中的 `<script>`
+/// - 跳过带 `src` 属性的外部脚本，以及非 JavaScript 的 `type`（如 `text/template`、`application/json`）
+/// - 代码块中的 EJS 标签替换为等长的占位符（替换后的代码分配在 `allocator` 中），模板语法不影响解析
+/// - 代码块记录在模板中的偏移量，lint 消息的位置会映射回模板
+pub fn extract_inline_scripts<'a>(
+  source_code: &'a str,
+  allocator: &'a Allocator,
+) -> Vec<JavaScriptSource<'a>> {
+  let mut sources = vec![];
+  let mut cursor = 0;
+
+  while let Some(tag_start) = find_script_tag(source_code, "<script", cursor, true) {
+    let Some(tag_end) = find_tag_end(source_code, tag_start) else {
+      break;
+    };
+
+    let Some(close_start) = find_script_tag(source_code, "</script", tag_end, false) else {
+      break;
+    };
+
+    cursor = close_start;
+
+    let attributes = &source_code[tag_start + "<script".len()..tag_end];
+    let script_start = tag_end + 1;
+    let script = &source_code[script_start..close_start];
+
+    let Some(source_type) = script_source_type(attributes) else {
+      continue;
+    };
+
+    if script.trim().is_empty() {
+      continue;
+    }
+
+    let script = if script.contains(TEMPLATE_TAG_OPEN) {
+      allocator.alloc_str(&replace_template_tags(script))
+    } else {
+      script
+    };
+
+    sources.push(JavaScriptSource::partial(
+      script,
+      source_type,
+      script_start as u32,
+    ));
+  }
+
+  sources
+}
+
+/// 是否包含 EJS 标签
+pub fn contains_template_tags(source_code: &str) -> bool {
+  source_code.contains(TEMPLATE_TAG_OPEN)
+}
+
+/// 把 EJS 标签替换为等长（字节数相同）的占位符，代码中其它位置的偏移量保持不变
+///
+/// - 输出标签（`<%=`、`<%-`）作为表达式保留：`var a = <%= data %>;` => `var a = 0          ;`
+/// - 其它标签（控制语句、注释等）替换为空格
+/// - 标签中的换行保留，行号保持不变
+fn replace_template_tags(script: &str) -> String {
+  let mut result = String::with_capacity(script.len());
+  let mut rest = script;
+
+  while let Some(start) = rest.find(TEMPLATE_TAG_OPEN) {
+    result.push_str(&rest[..start]);
+
+    let content_start = start + TEMPLATE_TAG_OPEN.len();
+
+    // 没有结束标签时一直替换到代码块末尾
+    let end = rest[content_start..]
+      .find(TEMPLATE_TAG_CLOSE)
+      .map_or(rest.len(), |i| content_start + i + TEMPLATE_TAG_CLOSE.len());
+
+    let tag = &rest[start..end];
+    let is_output = rest[content_start..].starts_with(['=', '-']);
+
+    for (i, c) in tag.char_indices() {
+      match c {
+        _ if i == 0 && is_output => result.push('0'),
+        '\n' | '\r' => result.push(c),
+        _ => result.extend(std::iter::repeat_n(' ', c.len_utf8())),
+      }
+    }
+
+    rest = &rest[end..];
+  }
+
+  result.push_str(rest);
+  result
+}
+
+/// 根据 `<script>` 的属性确定语言，不需要 lint 的脚本返回 None
+fn script_source_type(attributes: &str) -> Option<SourceType> {
+  // 外部脚本
+  if attribute_value(attributes, "src").is_some() {
+    return None;
+  }
+
+  match attribute_value(attributes, "type") {
+    None => Some(SourceType::cjs()),
+    Some(ty) if ty.eq_ignore_ascii_case("module") => Some(SourceType::mjs()),
+    Some(ty)
+      if ty.eq_ignore_ascii_case("text/javascript")
+        || ty.eq_ignore_ascii_case("application/javascript") =>
+    {
+      Some(SourceType::cjs())
+    }
+    Some(_) => None,
+  }
+}
+
+/// 读取属性值（属性名不区分大小写，支持单/双引号和无引号的值）
+fn attribute_value<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+  let mut cursor = 0;
+
+  while let Some(start) = find_ignore_ascii_case(attributes, name, cursor) {
+    cursor = start + name.len();
+
+    // 必须是完整的属性名
+    let preceded_by_space = attributes[..start]
+      .chars()
+      .next_back()
+      .is_none_or(|c| c.is_ascii_whitespace());
+
+    if !preceded_by_space {
+      continue;
+    }
+
+    let rest = attributes[cursor..].trim_start();
+
+    let Some(rest) = rest.strip_prefix('=') else {
+      // 布尔属性（如 `<script src>`）
+      if rest.is_empty() || rest.starts_with(|c: char| c.is_ascii_whitespace() || c == '/') {
+        return Some("");
+      }
+      continue;
+    };
+
+    let rest = rest.trim_start();
+
+    let value = match rest.chars().next() {
+      Some(quote @ ('"' | '\'')) => rest[1..].split(quote).next().unwrap_or_default(),
+      _ => rest
+        .split(|c: char| c.is_ascii_whitespace() || c == '/')
+        .next()
+        .unwrap_or_default(),
+    };
+
+    return Some(value.trim());
+  }
+
+  None
+}
+
+/// 查找 `<script` / `</script` 标签的位置
+///
+/// - 标签名之后必须是空白、`/` 或 `>`（`<scripts>` 不是 script 标签）
+/// - `skip_comments` 为 true 时跳过 HTML 注释（脚本内容中的 `<!--` 不是注释，查找结束标签时不跳过）
+fn find_script_tag(
+  source_code: &str,
+  tag: &str,
+  from: usize,
+  skip_comments: bool,
+) -> Option<usize> {
+  let mut cursor = from;
+
+  loop {
+    let start = find_ignore_ascii_case(source_code, tag, cursor)?;
+
+    if skip_comments
+      && let Some(comment_start) = source_code[cursor..start]
+        .find(COMMENT_OPEN)
+        .map(|i| cursor + i)
+    {
+      // 没有结束的注释一直到文件末尾
+      let comment_end = source_code[comment_start + COMMENT_OPEN.len()..].find(COMMENT_CLOSE)?;
+      cursor = comment_start + COMMENT_OPEN.len() + comment_end + COMMENT_CLOSE.len();
+      continue;
+    }
+
+    let is_tag_name_end = source_code[start + tag.len()..]
+      .chars()
+      .next()
+      .is_none_or(|c| c.is_ascii_whitespace() || c == '/' || c == '>');
+
+    if is_tag_name_end {
+      return Some(start);
+    }
+
+    cursor = start + tag.len();
+  }
+}
+
+/// 查找开始标签的结束位置（`>`），忽略引号中的 `>`（如 `<script data-x="a>b">`）
+fn find_tag_end(source_code: &str, tag_start: usize) -> Option<usize> {
+  let mut quote = None;
+
+  for (i, c) in source_code[tag_start..].char_indices() {
+    match (quote, c) {
+      (None, '"' | '\'') => quote = Some(c),
+      (None, '>') => return Some(tag_start + i),
+      (Some(q), _) if q == c => quote = None,
+      _ => {}
+    }
+  }
+
+  None
+}
+
+fn find_ignore_ascii_case(haystack: &str, needle: &str, from: usize) -> Option<usize> {
+  let haystack_bytes = haystack.as_bytes();
+  let needle = needle.as_bytes();
+
+  (from..=haystack_bytes.len().checked_sub(needle.len())?)
+    .find(|&i| haystack_bytes[i..i + needle.len()].eq_ignore_ascii_case(needle))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_extract_inline_scripts() {
+    let html = r#"<html>
+<head>
+  <script src="./vendor.js"></script>
+  <SCRIPT>var a = 1;</SCRIPT>
+  <script type="text/template"><div></div></script>
+  <script type="module">import "./a.js";</script>
+  <script>var b = <%= JSON.stringify(data) %>;</script>
+</head>
+</html>"#;
+
+    let allocator = Allocator::default();
+    let sources = extract_inline_scripts(html, &allocator);
+
+    assert_eq!(sources.len(), 3);
+    assert_eq!(sources[0].source_text, "var a = 1;");
+    assert_eq!(
+      &html[sources[0].start as usize..][..sources[0].source_text.len()],
+      "var a = 1;"
+    );
+    assert_eq!(sources[1].source_text, r#"import "./a.js";"#);
+    assert!(sources[1].source_type.is_module());
+
+    // EJS 标签替换为等长的占位符，位置仍然与模板一致
+    let tag = "<%= JSON.stringify(data) %>";
+    assert_eq!(
+      sources[2].source_text,
+      format!("var b = 0{};", " ".repeat(tag.len() - 1))
+    );
+    assert_eq!(
+      &html[sources[2].start as usize..][..sources[2].source_text.len()],
+      format!("var b = {};", tag)
+    );
+  }
+
+  #[test]
+  fn test_extract_inline_scripts_tags() {
+    let cases = [
+      // 引号中的 `>` 不结束开始标签
+      (
+        r#"<script data-x="a>b">var a = 1;</script>"#,
+        vec!["var a = 1;"],
+      ),
+      (
+        r#"<script data-x='a>b'>var a = 1;</script>"#,
+        vec!["var a = 1;"],
+      ),
+      // HTML 注释中的 script 不提取
+      (
+        "<!-- <script>var a = 1;</script> --><script>var b = 2;</script>",
+        vec!["var b = 2;"],
+      ),
+      ("<!-- <script>var a = 1;</script>", vec![]),
+      // 标签名之后必须是空白、`/` 或 `>`
+      (
+        "<scripts>var a = 1;</scripts><script>var b = 2;</script>",
+        vec!["var b = 2;"],
+      ),
+      (
+        "<script>var a = '</scripts>';</script>",
+        vec!["var a = '</scripts>';"],
+      ),
+    ];
+
+    for (html, expected) in cases {
+      let allocator = Allocator::default();
+      let sources = extract_inline_scripts(html, &allocator);
+
+      assert_eq!(
+        sources
+          .iter()
+          .map(|source| source.source_text)
+          .collect::<Vec<_>>(),
+        expected,
+        "{}",
+        html
+      );
+
+      for source in &sources {
+        assert_eq!(
+          &html[source.start as usize..][..source.source_text.len()],
+          source.source_text
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn test_replace_template_tags() {
+    let cases = [
+      ("var a = <%- html %>;", "var a = 0          ;"),
+      (
+        "<% if (a) { %>\nfoo();\n<% } %>",
+        "              \nfoo();\n       ",
+      ),
+      ("<%# 注释\n %>bar();", "          \n   bar();"),
+      ("var a = 1; <% unclosed", "var a = 1;            "),
+    ];
+
+    for (input, expected) in cases {
+      let output = replace_template_tags(input);
+      assert_eq!(output, expected, "{}", input);
+      assert_eq!(output.len(), input.len(), "{}", input);
+    }
+  }
+}
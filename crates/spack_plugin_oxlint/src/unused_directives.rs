@@ -0,0 +1,26 @@
+use oxc_linter::AllowWarnDeny;
+use strum_macros::EnumString;
+
+/// 未使用的 `eslint-disable` / `oxlint-disable` 指令的报告级别
+///
+/// - `off`: 不报告
+/// - `warn`: 报告为警告
+/// - `error`: 报告为错误（默认）
+#[derive(EnumString, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[strum(serialize_all = "camelCase")]
+pub enum UnusedDirectives {
+  Off,
+  Warn,
+  #[default]
+  Error,
+}
+
+impl From<UnusedDirectives> for Option<AllowWarnDeny> {
+  fn from(value: UnusedDirectives) -> Self {
+    match value {
+      UnusedDirectives::Off => None,
+      UnusedDirectives::Warn => Some(AllowWarnDeny::Warn),
+      UnusedDirectives::Error => Some(AllowWarnDeny::Deny),
+    }
+  }
+}